@@ -1,7 +1,9 @@
 #![allow(non_snake_case)]
 
 pub mod saveLoader;
+pub mod gen2SaveLoader;
 
 mod creatureData;
+mod saveData;
 mod addresses;
 mod utils;
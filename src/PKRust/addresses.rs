@@ -1,13 +1,81 @@
+/// Size of a full Gen 1 SRAM image (4 banks of 8 KiB)
+pub const SAVE_SIZE:            usize   = 0x8000;
+
 pub const CHECKSUM_START_ADDR:  usize   = 0x2598;
 pub const CHECKSUM_END_ADDR:    usize   = 0x3522;
 pub const CHECKSUM_RESULT:      usize   = 0x3523;
 
+// PC Bank Checksums
+// Banks 2 and 3 each hold 6 boxes, followed by a checksum for
+// the whole bank and then one checksum for each box.
+pub const BANK_CHECKSUM_OFF:    usize   = 0x1A4C;
+pub const BOX_CHECKSUM_OFF:     usize   = 0x1A4D;
+pub const BOX_SIZE:             usize   = 0x462;
+pub const BOXES_PER_BANK:       usize   = 6;
+pub const BOX_CAPACITY:         usize   = 20;
+pub const BANK_SIZE:            usize   = 0x2000;
+
 // General Starting Addresses
 pub const MONEY_ADDR:           usize   = 0x25F3;
+pub const COINS_ADDR:           usize   = 0x2850;
 pub const ID_ADDR:              usize   = 0x2605;
 pub const NAME_ADDR:            usize   = 0x2598;
+pub const RIVAL_ADDR:           usize   = 0x25F6;
 pub const PARTY_ADDR:           usize   = 0x2F2C;
 pub const PC_ADDR:              usize   = 0x4000;
+pub const BAG_ADDR:             usize   = 0x25C9;
+pub const POKEDEX_OWNED_ADDR:   usize   = 0x25A3;
+pub const POKEDEX_SEEN_ADDR:    usize   = 0x25B6;
+pub const OPTIONS_ADDR:         usize   = 0x2601;
+pub const BADGES_ADDR:          usize   = 0x2602;
+pub const PLAY_TIME_ADDR:       usize   = 0x2CED;
+pub const PC_ITEMS_ADDR:        usize   = 0x27E6;
+
+// Hall of Fame
+// Each record is 6 entries of 16 bytes: species, level, nickname and 3 bytes of padding
+pub const HOF_ADDR:             usize   = 0x0598;
+pub const HOF_COUNT_ADDR:       usize   = 0x284E;
+pub const HOF_RECORD_SIZE:      usize   = 0x60;
+pub const HOF_ENTRY_SIZE:       usize   = 0x10;
+
+// Player Position
+pub const CUR_MAP_ADDR:         usize   = 0x260A;
+/// Pointer into wOverworldMap for the top left block on screen (little endian)
+pub const VIEW_POINTER_ADDR:    usize   = 0x260B;
+pub const Y_COORD_ADDR:         usize   = 0x260D;
+pub const X_COORD_ADDR:         usize   = 0x260E;
+pub const Y_BLOCK_ADDR:         usize   = 0x260F;
+pub const X_BLOCK_ADDR:         usize   = 0x2610;
+pub const LAST_MAP_ADDR:        usize   = 0x2611;
+pub const LAST_BLACKOUT_ADDR:   usize   = 0x29C5;
+
+// Story Progress
+pub const EVENT_FLAGS_ADDR:     usize   = 0x29F3;
+pub const MISSABLES_ADDR:       usize   = 0x2852;
+pub const STARTER_ADDR:         usize   = 0x29C3;
+
+// Yellow
+// Pikachu's friendship is only used by Yellow, and is left as 0 by Red and Blue
+pub const PIKACHU_FRIENDSHIP_ADDR:usize = 0x271C;
+
+// Current PC Box
+// The box that is open in the PC is worked on in a copy in bank 1,
+// and is only copied back to banks 2 and 3 when the box is changed.
+// Bit 7 of the index is set once the boxes have been initialised.
+pub const CURRENT_BOX_ADDR:     usize   = 0x284C;
+pub const CURRENT_BOX_DATA_ADDR:usize   = 0x30C0;
+pub const NUM_BOXES:            usize   = 12;
+
+// Daycare
+// The daycare Pokemon is stored in the box format
+pub const DAYCARE_IN_USE_ADDR:  usize   = 0x2CF4;
+pub const DAYCARE_NICK_ADDR:    usize   = 0x2CF5;
+pub const DAYCARE_OTN_ADDR:     usize   = 0x2D00;
+pub const DAYCARE_PKMN_ADDR:    usize   = 0x2D0B;
+
+// Item list capacities
+pub const BAG_CAPACITY:         usize   = 20;
+pub const PC_ITEMS_CAPACITY:    usize   = 50;
 
 // PC Offsets     
 
@@ -21,10 +89,302 @@ pub const PC_NICK_OFF:          usize   = 0x386;
 // Pokemon Data Offsets   
 pub const NICK_OFF:             usize   = 0x152;
 pub const HP_OFF:               usize   = 0x01;
+pub const STATUS_OFF:           usize   = 0x04;
+pub const TYPE_OFF:             usize   = 0x05;
+pub const CATCH_RATE_OFF:       usize   = 0x07;
 pub const MOVE_OFF:             usize   = 0x08;
 pub const PP_OFF:               usize   = 0x1D;
 pub const OT_OFF:               usize   = 0x0C;
+pub const EXP_OFF:              usize   = 0x0E;
 pub const OTN_OFF:              usize   = 0x110;
 pub const EV_OFF:               usize   = 0x11;
 pub const STAT_OFF:             usize   = 0x22;
-pub const IV_OFF:               usize   = 0x1B;
\ No newline at end of file
+pub const IV_OFF:               usize   = 0x1B;
+// Save Layouts
+// The Japanese games have 6 byte names (5 characters and the terminator), and 8 boxes of 30 Pokemon,
+// so everything after the player name is moved. Only the addresses that differ are kept in the layout,
+// the rest (such as the Pokemon data offsets) are the same in every region.
+
+/// Which release of the games a save is from
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum GameRegion {
+    International,
+    Japanese,
+}
+
+#[derive(Debug)]
+/// The addresses and sizes of a save, for one region
+pub struct SaveLayout {
+    pub region:                 GameRegion,
+
+    // Name lengths, in bytes and characters
+    pub nameLength:             usize,
+    pub playerNameLength:       usize,
+
+    // Main data checksum
+    pub checksumEndAddr:        usize,
+    pub checksumResult:         usize,
+
+    // PC boxes
+    pub numBoxes:               usize,
+    pub boxesPerBank:           usize,
+    pub boxCapacity:            usize,
+    pub boxSize:                usize,
+    pub bankChecksumOff:        usize,
+    pub boxChecksumOff:         usize,
+    pub pcPkmnOff:              usize,
+    pub pcTrainerOff:           usize,
+    pub pcNickOff:              usize,
+
+    // Party
+    pub partyAddr:              usize,
+    pub partyOTNOff:            usize,
+    pub partyNickOff:           usize,
+
+    // General
+    pub nameAddr:               usize,
+    pub rivalAddr:              usize,
+    pub moneyAddr:              usize,
+    pub coinsAddr:              usize,
+    pub idAddr:                 usize,
+    pub bagAddr:                usize,
+    pub pcItemsAddr:            usize,
+    pub pokedexOwnedAddr:       usize,
+    pub pokedexSeenAddr:        usize,
+    pub optionsAddr:            usize,
+    pub badgesAddr:             usize,
+    pub playTimeAddr:           usize,
+    pub hofCountAddr:           usize,
+
+    // Hall of Fame
+    pub hofAddr:                usize,
+    pub hofRecordSize:          usize,
+    pub hofEntrySize:           usize,
+
+    // Player Position
+    pub curMapAddr:             usize,
+    pub viewPointerAddr:        usize,
+    pub yCoordAddr:             usize,
+    pub xCoordAddr:             usize,
+    pub yBlockAddr:             usize,
+    pub xBlockAddr:             usize,
+    pub lastMapAddr:            usize,
+    pub lastBlackoutAddr:       usize,
+
+    // Story Progress
+    pub eventFlagsAddr:         usize,
+    pub missablesAddr:          usize,
+    pub starterAddr:            usize,
+
+    // Yellow
+    pub pikachuFriendshipAddr:  usize,
+
+    // Current PC Box
+    pub currentBoxAddr:         usize,
+    pub currentBoxDataAddr:     usize,
+
+    // Daycare
+    pub daycareInUseAddr:       usize,
+    pub daycareNickAddr:        usize,
+    pub daycareOTNAddr:         usize,
+    pub daycarePkmnAddr:        usize,
+}
+impl SaveLayout {
+    /// Returns the address of a PC box in banks 2 and 3
+    pub fn boxAddr(&self, pcBox: usize) -> usize {
+        return PC_ADDR + self.boxSize*(pcBox%self.boxesPerBank) + BANK_SIZE*(pcBox/self.boxesPerBank);
+    }
+
+    /// Returns the address of a Pokemon in a Hall of Fame record
+    pub fn hofEntryAddr(&self, record: usize, slot: usize) -> usize {
+        return self.hofAddr + self.hofRecordSize*record + self.hofEntrySize*slot;
+    }
+}
+
+/// English and other non-Japanese releases
+pub const INTERNATIONAL: SaveLayout = SaveLayout {
+    region:                 GameRegion::International,
+    nameLength:             0xB,
+    playerNameLength:       7,
+    checksumEndAddr:        CHECKSUM_END_ADDR,
+    checksumResult:         CHECKSUM_RESULT,
+    numBoxes:               NUM_BOXES,
+    boxesPerBank:           BOXES_PER_BANK,
+    boxCapacity:            BOX_CAPACITY,
+    boxSize:                BOX_SIZE,
+    bankChecksumOff:        BANK_CHECKSUM_OFF,
+    boxChecksumOff:         BOX_CHECKSUM_OFF,
+    pcPkmnOff:              PC_PKMN_OFF,
+    pcTrainerOff:           PC_TRAINER_OFF,
+    pcNickOff:              PC_NICK_OFF,
+    partyAddr:              PARTY_ADDR,
+    partyOTNOff:            OTN_OFF,
+    partyNickOff:           NICK_OFF,
+    nameAddr:               NAME_ADDR,
+    rivalAddr:              RIVAL_ADDR,
+    moneyAddr:              MONEY_ADDR,
+    coinsAddr:              COINS_ADDR,
+    idAddr:                 ID_ADDR,
+    bagAddr:                BAG_ADDR,
+    pcItemsAddr:            PC_ITEMS_ADDR,
+    pokedexOwnedAddr:       POKEDEX_OWNED_ADDR,
+    pokedexSeenAddr:        POKEDEX_SEEN_ADDR,
+    optionsAddr:            OPTIONS_ADDR,
+    badgesAddr:             BADGES_ADDR,
+    playTimeAddr:           PLAY_TIME_ADDR,
+    hofCountAddr:           HOF_COUNT_ADDR,
+    hofAddr:                HOF_ADDR,
+    hofRecordSize:          HOF_RECORD_SIZE,
+    hofEntrySize:           HOF_ENTRY_SIZE,
+    curMapAddr:             CUR_MAP_ADDR,
+    viewPointerAddr:        VIEW_POINTER_ADDR,
+    yCoordAddr:             Y_COORD_ADDR,
+    xCoordAddr:             X_COORD_ADDR,
+    yBlockAddr:             Y_BLOCK_ADDR,
+    xBlockAddr:             X_BLOCK_ADDR,
+    lastMapAddr:            LAST_MAP_ADDR,
+    lastBlackoutAddr:       LAST_BLACKOUT_ADDR,
+    eventFlagsAddr:         EVENT_FLAGS_ADDR,
+    missablesAddr:          MISSABLES_ADDR,
+    starterAddr:            STARTER_ADDR,
+    pikachuFriendshipAddr:  PIKACHU_FRIENDSHIP_ADDR,
+    currentBoxAddr:         CURRENT_BOX_ADDR,
+    currentBoxDataAddr:     CURRENT_BOX_DATA_ADDR,
+    daycareInUseAddr:       DAYCARE_IN_USE_ADDR,
+    daycareNickAddr:        DAYCARE_NICK_ADDR,
+    daycareOTNAddr:         DAYCARE_OTN_ADDR,
+    daycarePkmnAddr:        DAYCARE_PKMN_ADDR,
+};
+
+/// Japanese Red, Green, Blue and Yellow
+/// 
+/// More Info [here](https://bulbapedia.bulbagarden.net/wiki/Save_data_structure_(Generation_I))
+pub const JAPANESE: SaveLayout = SaveLayout {
+    region:                 GameRegion::Japanese,
+    nameLength:             0x6,
+    playerNameLength:       5,
+    checksumEndAddr:        0x3593,
+    checksumResult:         0x3594,
+    numBoxes:               8,
+    boxesPerBank:           4,
+    boxCapacity:            30,
+    boxSize:                0x566,
+    bankChecksumOff:        0x1598,
+    boxChecksumOff:         0x1599,
+    // The species list holds 30 Pokemon, and the names are 6 bytes
+    pcPkmnOff:              0x20,
+    pcTrainerOff:           0x3FE,
+    pcNickOff:              0x4B2,
+    partyAddr:              0x2ED5,
+    partyOTNOff:            0x110,
+    partyNickOff:           0x134,
+    nameAddr:               NAME_ADDR,
+    rivalAddr:              0x25F1,
+    moneyAddr:              0x25EE,
+    coinsAddr:              0x2846,
+    idAddr:                 0x25FB,
+    bagAddr:                0x25C4,
+    pcItemsAddr:            0x27DC,
+    pokedexOwnedAddr:       0x259E,
+    pokedexSeenAddr:        0x25B1,
+    optionsAddr:            0x25F7,
+    badgesAddr:             0x25F8,
+    playTimeAddr:           0x2CA0,
+    hofCountAddr:           0x2844,
+    // Bank 0 only holds the sprite buffers before the Hall of Fame, which are the same size in every version.
+    // Entries are taken to keep their 16 byte stride, with the shorter name leaving more padding.
+    // This hasn't been confirmed, so the Hall of Fame can be read but not edited in Japanese saves.
+    hofAddr:                HOF_ADDR,
+    hofRecordSize:          HOF_RECORD_SIZE,
+    hofEntrySize:           HOF_ENTRY_SIZE,
+    curMapAddr:             0x2600,
+    viewPointerAddr:        0x2601,
+    yCoordAddr:             0x2603,
+    xCoordAddr:             0x2604,
+    yBlockAddr:             0x2605,
+    xBlockAddr:             0x2606,
+    lastMapAddr:            0x2607,
+    lastBlackoutAddr:       0x29BB,
+    eventFlagsAddr:         0x29E9,
+    missablesAddr:          0x2848,
+    starterAddr:            0x29B9,
+    pikachuFriendshipAddr:  0x2712,
+    currentBoxAddr:         0x2842,
+    currentBoxDataAddr:     0x302D,
+    daycareInUseAddr:       0x2CA7,
+    daycareNickAddr:        0x2CA8,
+    daycareOTNAddr:         0x2CAE,
+    daycarePkmnAddr:        0x2CB4,
+};
+
+
+// ========   GEN 2   ========
+// Gold, Silver and Crystal use a 32 KiB save as well, but the main data is
+// covered by a 16 bit checksum, and the Pokemon structure is 48 bytes long.
+// More Info [here](https://bulbapedia.bulbagarden.net/wiki/Save_data_structure_(Generation_II))
+
+pub const GEN2_CHECKSUM_START_ADDR: usize   = 0x2009;
+
+// Party
+// The party is a count, a 7 byte species list and then 6 Pokemon,
+// followed by 6 Original Trainer names and 6 nicknames.
+pub const GEN2_PKMN_SIZE:       usize   = 0x30;
+pub const GEN2_PARTY_PKMN_OFF:  usize   = 0x08;
+pub const GEN2_PARTY_OTN_OFF:   usize   = 0x128;
+pub const GEN2_PARTY_NICK_OFF:  usize   = 0x16A;
+
+// Gen 2 Pokemon Data Offsets
+pub const GEN2_ITEM_OFF:        usize   = 0x01;
+pub const GEN2_MOVE_OFF:        usize   = 0x02;
+pub const GEN2_OT_OFF:          usize   = 0x06;
+pub const GEN2_EXP_OFF:         usize   = 0x08;
+pub const GEN2_EV_OFF:          usize   = 0x0B;
+pub const GEN2_IV_OFF:          usize   = 0x15;
+pub const GEN2_PP_OFF:          usize   = 0x17;
+pub const GEN2_FRIENDSHIP_OFF:  usize   = 0x1B;
+pub const GEN2_POKERUS_OFF:     usize   = 0x1C;
+/// Only Crystal fills in the caught data, Gold and Silver leave it as 0
+pub const GEN2_CAUGHT_OFF:      usize   = 0x1D;
+pub const GEN2_LEVEL_OFF:       usize   = 0x1F;
+pub const GEN2_HP_OFF:          usize   = 0x22;
+/// Max HP, Attack, Defense, Speed, Special Attack and Special Defense
+pub const GEN2_STAT_OFF:        usize   = 0x24;
+
+#[derive(Debug)]
+/// The addresses of a Gen 2 save, for one version
+pub struct Gen2Layout {
+    pub name:                   &'static str,
+
+    pub checksumEndAddr:        usize,
+    pub checksumAddr:           usize,
+
+    pub idAddr:                 usize,
+    pub nameAddr:               usize,
+    pub rivalAddr:              usize,
+    pub moneyAddr:              usize,
+    pub partyAddr:              usize,
+}
+
+/// Gold and Silver
+pub const GOLD_SILVER: Gen2Layout = Gen2Layout {
+    name:                   "Gold/Silver",
+    checksumEndAddr:        0x2D68,
+    checksumAddr:           0x2D69,
+    idAddr:                 0x2009,
+    nameAddr:               0x200B,
+    rivalAddr:              0x2021,
+    moneyAddr:              0x23DB,
+    partyAddr:              0x288A,
+};
+
+/// Crystal, which added the player's gender and moved most of the save along
+pub const CRYSTAL: Gen2Layout = Gen2Layout {
+    name:                   "Crystal",
+    checksumEndAddr:        0x2B82,
+    checksumAddr:           0x2D0D,
+    idAddr:                 0x2009,
+    nameAddr:               0x200B,
+    rivalAddr:              0x2021,
+    moneyAddr:              0x23DC,
+    partyAddr:              0x2865,
+};
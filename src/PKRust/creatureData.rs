@@ -5,9 +5,14 @@
 /// - Moves
 /// - Various Stats
 /// - Species
-
+/// - Growth Rates
+/// - Status Conditions
+/// - Gen 2 Pokemon
 pub mod pokemon;
 pub mod pokemonType;
 pub mod pokemonMove;
 pub mod pokemonSpecies;
-pub mod pokemonStats;
\ No newline at end of file
+pub mod pokemonStats;
+pub mod pokemonGrowthRate;
+pub mod pokemonStatusCondition;
+pub mod gen2Pokemon;
\ No newline at end of file
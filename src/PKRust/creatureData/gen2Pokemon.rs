@@ -0,0 +1,322 @@
+use super::pokemonMove::Move;
+use super::pokemonSpecies::Species;
+use super::pokemonStats::{Stats, IVs, EVs};
+
+/// Gender ratios that aren't a chance, as stored in the species data
+const GENDER_MALE_ONLY:     u8 = 0;
+const GENDER_FEMALE_ONLY:   u8 = 254;
+const GENDER_UNKNOWN:       u8 = 255;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Gender {
+    Male,
+    Female,
+    Genderless,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/// The time of day a Pokemon was caught at, which Crystal records
+pub enum TimeOfDay {
+    Unknown,
+    Morning,
+    Day,
+    Night,
+}
+
+#[derive(Debug, PartialEq)]
+/// Where, when and at what level a Pokemon was caught.
+///
+/// Only Crystal fills this in, so it is all 0 for Pokemon from Gold and Silver.
+/// More Info [here](https://bulbapedia.bulbagarden.net/wiki/Pok%C3%A9mon_data_structure_(Generation_II)#Caught_data)
+pub struct CaughtData {
+    time:       TimeOfDay,
+    level:      u8,
+    /// The gender of the Original Trainer, as Crystal has a female player
+    otFemale:   bool,
+    location:   u8,
+}
+impl CaughtData {
+    /// Constructor for the caught data, from the 2 bytes in the Pokemon structure
+    ///
+    /// The first byte is the time of day (bits 6-7) and the level (bits 0-5),
+    /// and the second is the Original Trainers gender (bit 7) and the location (bits 0-6).
+    pub fn get(data: [u8;2]) -> CaughtData {
+        let time = match data[0] >> 6 {
+            1 => TimeOfDay::Morning,
+            2 => TimeOfDay::Day,
+            3 => TimeOfDay::Night,
+            _ => TimeOfDay::Unknown,
+        };
+
+        return CaughtData{time, level: data[0] & 0x3F, otFemale: data[1] & 0x80 != 0, location: data[1] & 0x7F};
+    }
+
+    pub fn to_string(&self) -> String {
+        if self.level == 0 && self.location == 0 {
+            return String::from("Unknown");
+        }
+
+        return format!("Level {} at location {} ({:?})", self.level, self.location, self.time);
+    }
+
+    pub fn getTime(&self) -> &TimeOfDay {
+        return &self.time;
+    }
+
+    pub fn getLevel(&self) -> &u8 {
+        return &self.level;
+    }
+
+    pub fn isOTFemale(&self) -> &bool {
+        return &self.otFemale;
+    }
+
+    pub fn getLocation(&self) -> &u8 {
+        return &self.location;
+    }
+}
+
+#[derive(Debug)]
+/// A Pokemon from a Gen 2 save.
+///
+/// This is the Gen 1 structure with a held item, friendship, Pokerus and caught data added.
+/// The Special stat was split in two, so `stats` holds the Special Attack and
+/// the Special Defense is kept apart. Stat Experience and DVs still have one Special value.
+pub struct Gen2Pokemon {
+    nickname:   String,
+    species:    Species,
+    level:      i8,
+    /// The index of the held item, or 0 if nothing is held
+    heldItem:   u8,
+    moves:      Vec<Move>,
+    /// Original Trainer ID
+    ot:         u16,
+    /// Original Trainer Name
+    otn:        String,
+    exp:        u32,
+    hp:         i16,
+    evs:        EVs,
+    ivs:        IVs,
+    stats:      Stats,
+    spcDef:     u16,
+    friendship: u8,
+    /// The strain (bits 4-7) and the days left (bits 0-3)
+    pokerus:    u8,
+    caughtData: CaughtData,
+}
+impl Gen2Pokemon {
+
+    /// Constructor for a Gen 2 Pokemon, when being read from a save file
+    ///
+    /// `statArr` is HP, Attack, Defense, Speed, Special Attack and Special Defense.
+    #[allow(clippy::too_many_arguments)]
+    pub fn get(index: i16, level: i8, nickname: String, heldItem: u8, moves: Vec<Move>, ot: u16, otn: String, exp: u32, hp: i16, evArr: [u16;5], ivArr: [u16;5], statArr: [u16;6], friendship: u8, pokerus: u8, caughtData: CaughtData) -> Result<Gen2Pokemon, String> {
+        let species = Species::parseGen2(index)?;
+
+        let evs = EVs::setAll(evArr);
+        let ivs = IVs::setAll(ivArr);
+        let stats = Stats::set([statArr[0], statArr[1], statArr[2], statArr[3], statArr[4]]);
+
+        return Ok(Gen2Pokemon{nickname, species, level, heldItem, moves, ot, otn, exp, hp, evs, ivs, stats, spcDef: statArr[5], friendship, pokerus, caughtData});
+    }
+
+    // ========   GETTERS   ========
+
+    /// Returns a string with all of the Pokemon's details, the same as `Pokemon::getDetails`
+    /// with the Gen 2 additions on the line after the moves.
+    pub fn getDetails(&self) -> String {
+        let basicDetails = format!("{:12} {:12} LVL:{} Current HP: {}\n",
+                                        self.species.getName(),
+                                        self.nickname,
+                                        self.level,
+                                        self.hp
+                                    );
+        let gen2Details = format!("\tHeld Item: {} Friendship: {} Gender: {:?} Shiny: {} Pokerus: {}\n\tCaught: {}\n",
+                                        self.heldItem,
+                                        self.friendship,
+                                        self.getGender(),
+                                        self.isShiny(),
+                                        self.hasPokerus(),
+                                        self.caughtData.to_string()
+                                    );
+
+        let moves: Vec<String> = self.moves.iter().map(|pkmnMove| pkmnMove.to_string()).collect();
+        let moveDetails = format!("\t{}\n\t{}\n\t{}\n\t{}\n\n", moves[0], moves[1], moves[2], moves[3]);
+
+        return format!("{}{}{}\n{}\tSPCL DEF: {}\n\n{}\n{}", basicDetails, gen2Details, moveDetails, self.stats.to_string(), self.spcDef, self.evs.to_string(), self.ivs.to_string());
+    }
+
+    pub fn getNickname(&self) -> &String {
+        return &self.nickname;
+    }
+
+    pub fn getSpecies(&self) -> &Species {
+        return &self.species;
+    }
+
+    pub fn getLevel(&self) -> &i8 {
+        return &self.level;
+    }
+
+    pub fn getHeldItem(&self) -> &u8 {
+        return &self.heldItem;
+    }
+
+    pub fn getMoves(&self) -> &Vec<Move> {
+        return &self.moves;
+    }
+
+    pub fn getOTID(&self) -> &u16 {
+        return &self.ot;
+    }
+
+    pub fn getOTN(&self) -> &String {
+        return &self.otn;
+    }
+
+    pub fn getExp(&self) -> &u32 {
+        return &self.exp;
+    }
+
+    pub fn getHP(&self) -> &i16 {
+        return &self.hp;
+    }
+
+    pub fn getEVs(&self) -> &EVs {
+        return &self.evs;
+    }
+
+    pub fn getIVs(&self) -> &IVs {
+        return &self.ivs;
+    }
+
+    /// Getter for the stats, where the Special is the Special Attack
+    pub fn getStats(&self) -> &Stats {
+        return &self.stats;
+    }
+
+    pub fn getSPCDEF(&self) -> &u16 {
+        return &self.spcDef;
+    }
+
+    pub fn getFriendship(&self) -> &u8 {
+        return &self.friendship;
+    }
+
+    pub fn getPokerus(&self) -> &u8 {
+        return &self.pokerus;
+    }
+
+    /// A Pokemon has had Pokerus if it has a strain, even once the days have run out
+    pub fn hasPokerus(&self) -> bool {
+        return self.pokerus != 0;
+    }
+
+    /// Pokerus is cured once the days run out, but the strain is kept
+    pub fn isPokerusCured(&self) -> bool {
+        return self.pokerus >> 4 != 0 && self.pokerus & 0x0F == 0;
+    }
+
+    pub fn getCaughtData(&self) -> &CaughtData {
+        return &self.caughtData;
+    }
+
+    /// Gender is worked out from the Attack and Speed DVs, which are
+    /// female if they are at or under the species gender ratio.
+    ///
+    /// More Info [here](https://bulbapedia.bulbagarden.net/wiki/Individual_values#Gender)
+    pub fn getGender(&self) -> Gender {
+        let ratio = self.species.getGenderRatio().unwrap_or(GENDER_UNKNOWN);
+        let genderDVs = (self.ivs.getATK() << 4 | self.ivs.getSPD()) as u8;
+
+        return match ratio {
+            GENDER_UNKNOWN      => Gender::Genderless,
+            GENDER_MALE_ONLY    => Gender::Male,
+            GENDER_FEMALE_ONLY  => Gender::Female,
+            _ if genderDVs <= ratio => Gender::Female,
+            _                   => Gender::Male,
+        };
+    }
+
+    /// A Pokemon is shiny if its Defense, Speed and Special DVs are 10,
+    /// and its Attack DV is 2, 3, 6, 7, 10, 11, 14 or 15.
+    ///
+    /// More Info [here](https://bulbapedia.bulbagarden.net/wiki/Individual_values#Shininess)
+    pub fn isShiny(&self) -> bool {
+        return *self.ivs.getDEF() == 10 && *self.ivs.getSPD() == 10 && *self.ivs.getSPC() == 10 && self.ivs.getATK() & 0x2 != 0;
+    }
+}
+
+
+#[cfg(test)]
+mod gen2PkmnTests {
+    use super::*;
+
+    /// Makes a Gen 2 Pokemon with the given species and DVs, and nothing else set
+    fn testPokemon(index: i16, ivs: [u16;5]) -> Gen2Pokemon {
+        return Gen2Pokemon::get(index, 5, String::from("Test"), 0,
+                                vec![Move::empty(), Move::empty(), Move::empty(), Move::empty()],
+                                0, String::from("Gold"), 0, 20, [0;5], ivs, [0;6], 70, 0,
+                                CaughtData::get([0, 0])).unwrap();
+    }
+
+    #[test]
+    fn isShiny_Correct() {
+        // The famous red Gyarados
+        let shiny = testPokemon(130, [0, 10, 10, 10, 10]);
+        let notShiny = testPokemon(130, [0, 9, 10, 10, 10]);
+
+        assert_eq!(shiny.isShiny(), true);
+        assert_eq!(notShiny.isShiny(), false);
+    }
+
+    #[test]
+    fn getGender_Ratio() {
+        // Cyndaquil is 1 in 8 female, so only an Attack DV of 0 or 1 is female
+        assert_eq!(testPokemon(155, [0, 1, 0, 15, 0]).getGender(), Gender::Female);
+        assert_eq!(testPokemon(155, [0, 2, 0, 0, 0]).getGender(), Gender::Male);
+        // Pikachu is half and half
+        assert_eq!(testPokemon(25, [0, 7, 0, 15, 0]).getGender(), Gender::Female);
+        assert_eq!(testPokemon(25, [0, 8, 0, 0, 0]).getGender(), Gender::Male);
+    }
+
+    #[test]
+    fn getGender_Fixed() {
+        assert_eq!(testPokemon(128, [0;5]).getGender(), Gender::Male);
+        assert_eq!(testPokemon(241, [15;5]).getGender(), Gender::Female);
+        assert_eq!(testPokemon(201, [0;5]).getGender(), Gender::Genderless);
+    }
+
+    #[test]
+    fn pokerus_Correct() {
+        let mut pokemon = testPokemon(1, [0;5]);
+        assert_eq!(pokemon.hasPokerus(), false);
+
+        // Strain 1 with 3 days left
+        pokemon.pokerus = 0x13;
+        assert_eq!(pokemon.hasPokerus(), true);
+        assert_eq!(pokemon.isPokerusCured(), false);
+
+        pokemon.pokerus = 0x10;
+        assert_eq!(pokemon.isPokerusCured(), true);
+    }
+
+    #[test]
+    fn caughtData_Correct() {
+        // Caught in the morning at level 5, at location 1 by a male trainer
+        let caughtData = CaughtData::get([0x45, 0x01]);
+
+        assert_eq!(caughtData.getTime(), &TimeOfDay::Morning);
+        assert_eq!(caughtData.getLevel(), &5);
+        assert_eq!(caughtData.getLocation(), &1);
+        assert_eq!(caughtData.isOTFemale(), &false);
+        assert_eq!(CaughtData::get([0, 0]).to_string(), "Unknown");
+    }
+
+    #[test]
+    fn get_IncorrectSpecies() {
+        let result = Gen2Pokemon::get(0, 5, String::from("Test"), 0, Vec::new(), 0, String::from("Gold"), 0, 20, [0;5], [0;5], [0;6], 70, 0, CaughtData::get([0, 0]));
+
+        assert_eq!(result.unwrap_err(), "Gen 2 Species with ID 0 not found.");
+    }
+}
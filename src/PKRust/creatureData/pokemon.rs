@@ -1,7 +1,10 @@
 use super::pokemonMove::Move;
 use super::pokemonSpecies::Species;
 use super::pokemonStats::{Stats, IVs, EVs};
-use super::super::utils::formatError;
+use super::pokemonGrowthRate::{GrowthRate, MAX_LEVEL};
+use super::pokemonStatusCondition::StatusCondition;
+use super::pokemonType::Type;
+use super::super::utils::{formatError, textEncode, kanaEncode};
 
 
 #[derive(Debug)]
@@ -9,27 +12,39 @@ pub struct Pokemon {
     nickname:   String,
     species:    Species,
     level:      i8,
+    exp:        u32,
     moves:      Vec<Move>,
     /// Original Trainer ID
     ot:         u16,
     /// Original Trainer Name
     otn:        String,
     hp:         i16,
+    /// The raw status byte, which is only changed by setStatus so that bits the game set are kept
+    status:     u8,
+    /// The two type IDs stored in the Pokemon, which the game uses in battle instead of the species'
+    types:      [u8;2],
+    /// The catch rate stored in the Pokemon, which Gen 2 reads as a held item
+    catchRate:  u8,
     evs:        EVs,
     ivs:        IVs,
     stats:      Stats,
+    /// The nickname and Original Trainer name as they were read from the save, until they are set.
+    /// Characters that can't be decoded are kept this way when the Pokemon is written back.
+    encodedNickname: Option<[i16;11]>,
+    encodedOTN:      Option<[i16;11]>,
 }
 impl Pokemon {
 
     /// Constructor for a Pokemon, when being read from a save file
-    pub fn get(index: i16, level:i8, nickname: String, moves: Vec<Move>, ot: u16, otn: String, hp: i16, evArr: [u16;5], ivArr: [u16;5], statArr: [u16;5]) -> Pokemon {
+    #[allow(clippy::too_many_arguments)]
+    pub fn get(index: i16, level:i8, exp: u32, nickname: String, moves: Vec<Move>, ot: u16, otn: String, hp: i16, status: u8, types: [u8;2], catchRate: u8, evArr: [u16;5], ivArr: [u16;5], statArr: [u16;5]) -> Pokemon {
         let species = Species::parse(index).unwrap();
 
         let evs = EVs::setAll(evArr);
         let ivs = IVs::setAll(ivArr);
         let stats = Stats::set([statArr[0], statArr[1], statArr[2], statArr[3], statArr[4]]);
         
-        return Pokemon{nickname, species, level, moves, ot, otn, hp, evs, ivs, stats};
+        return Pokemon{nickname, species, level, exp, moves, ot, otn, hp, status, types, catchRate, evs, ivs, stats, encodedNickname: None, encodedOTN: None};
     }
 
     /// Function for making a blank Pokemon
@@ -37,10 +52,14 @@ impl Pokemon {
         return Pokemon::get(
             0x99,
             10,
+            560,
             "Bobsaur".to_string(),
             vec![Move::empty(), Move::empty(), Move::empty(), Move::empty()],
             2, "Test Ketchum".to_string(),
             100,
+            0,
+            [0x16, 0x03],
+            45,
             [0;5],
             [0;5],
             [0;5]
@@ -92,6 +111,30 @@ impl Pokemon {
         return &self.level;
     }
 
+    /// Getter for the Pokemon's total experience
+    pub fn getExp(&self) -> &u32 {
+        return &self.exp;
+    }
+
+    /// Returns the growth rate of the Pokemon's species
+    fn getGrowthRate(&self) -> &GrowthRate {
+        return self.species.getGrowthRate().as_ref().expect("Gen 1 species always have a growth rate");
+    }
+
+    /// Checks that the Pokemon's level matches its experience.
+    /// 
+    /// The game works the level out from the experience when a Pokemon
+    /// gains experience, so an edited save can have them disagree.
+    pub fn checkExp(&self) -> Result<bool, String> {
+        let expLevel = self.getGrowthRate().levelForExp(self.exp);
+
+        if expLevel != self.level {
+            return Err(formatError(format!("{} is level {}, but has {} EXP, which is level {}", self.nickname, self.level, self.exp, expLevel)));
+        }
+
+        return Ok(true);
+    }
+
     pub fn getMoves(&self) -> &Vec<Move> {
         return &self.moves;
     }
@@ -104,10 +147,96 @@ impl Pokemon {
         return &self.otn;
     }
 
+    /// Getter for the nickname as it was read from the save, if it hasn't been set since
+    pub fn getEncodedNickname(&self) -> &Option<[i16;11]> {
+        return &self.encodedNickname;
+    }
+
+    /// Getter for the Original Trainer name as it was read from the save, if it hasn't been set since
+    pub fn getEncodedOTN(&self) -> &Option<[i16;11]> {
+        return &self.encodedOTN;
+    }
+
     pub fn getHP(&self) -> &i16 {
         return &self.hp;
     }
 
+    /// Returns the status condition the game shows for the Pokemon
+    pub fn getStatus(&self) -> StatusCondition {
+        return StatusCondition::get(self.status);
+    }
+
+    /// Getter for the raw status byte, as it is stored in the save
+    pub fn getStatusByte(&self) -> &u8 {
+        return &self.status;
+    }
+
+    /// Checks that the status byte holds a single status that the game can set.
+    /// 
+    /// Edited saves can set more than one status bit, which the game never does.
+    pub fn checkStatus(&self) -> Result<bool, String> {
+        if !StatusCondition::isLegal(self.status) {
+            return Err(formatError(format!("{} has the status byte 0x{:02X}, which is more than one status", self.nickname, self.status)));
+        }
+
+        return Ok(true);
+    }
+
+    /// Getter for the Gen 1 type IDs stored in the Pokemon
+    pub fn getTypes(&self) -> &[u8;2] {
+        return &self.types;
+    }
+
+    /// Returns the types stored in the Pokemon, decoded from their Gen 1 IDs.
+    /// 
+    /// Glitched Pokemon can have IDs that aren't a type, which returns an error.
+    pub fn getTyping(&self) -> Result<[Type;2], String> {
+        return Ok([Type::from_gen1_id(self.types[0])?, Type::from_gen1_id(self.types[1])?]);
+    }
+
+    /// Returns two Gen 1 type IDs as names, such as "Grass/Poison", using the ID for ones that aren't a type
+    fn typeNames(types: &[u8;2]) -> String {
+        let names: Vec<String> = types.iter()
+                                    .map(|id| Type::from_gen1_id(*id).map(|typing| format!("{:?}", typing)).unwrap_or(format!("0x{:02X}", id)))
+                                    .collect();
+
+        return names.join("/");
+    }
+
+    /// Getter for the catch rate stored in the Pokemon
+    pub fn getCatchRate(&self) -> &u8 {
+        return &self.catchRate;
+    }
+
+    /// Checks that the types stored in the Pokemon match its species.
+    /// 
+    /// The game copies the types from the species when a Pokemon is made, and never changes them,
+    /// so a mismatch is a sign of a glitched or hacked Pokemon.
+    pub fn checkTypes(&self) -> Result<bool, String> {
+        let speciesTypes = self.species.getGen1Types().expect("Gen 1 species always have Gen 1 types");
+
+        if self.types != speciesTypes {
+            return Err(formatError(format!("{} is {}, but {} is {}", self.nickname, Self::typeNames(&self.types), self.species.getName(), Self::typeNames(&speciesTypes))));
+        }
+
+        return Ok(true);
+    }
+
+    /// Checks that the catch rate stored in the Pokemon matches its species.
+    /// 
+    /// Unlike the types, this is only a possible sign of a hacked Pokemon.
+    /// The game doesn't update the catch rate when a Pokemon evolves,
+    /// and Pokemon traded back from Gen 2 have their held item here instead.
+    pub fn checkCatchRate(&self) -> Result<bool, String> {
+        let speciesCatchRate = self.species.getCatchRate().expect("Gen 1 species always have a catch rate");
+
+        if self.catchRate != speciesCatchRate {
+            return Err(formatError(format!("{} has a catch rate of {}, but {} has a catch rate of {}", self.nickname, self.catchRate, self.species.getName(), speciesCatchRate)));
+        }
+
+        return Ok(true);
+    }
+
     pub fn getEVs(&self) -> &EVs {
         return &self.evs;
     }
@@ -120,23 +249,41 @@ impl Pokemon {
         return &self.stats;
     }
 
+    /// Calculates what the Pokemon's stats should be, from its species, level, IVs and EVs
+    pub fn calculateStats(&self) -> Stats {
+        return Stats::calculate(&self.species, self.level, &self.ivs, &self.evs).expect("Gen 1 species always have base stats");
+    }
+
+    /// Checks that the stored stats match the calculated ones.
+    /// 
+    /// The game only recalculates a party Pokemon's stats when it levels up or
+    /// leaves the PC, so Stat Experience gained since then isn't counted yet.
+    pub fn hasCalculatedStats(&self) -> bool {
+        return self.stats == self.calculateStats();
+    }
+
     // ========   SETTERS   ========
 
     /// Setter for Pokemon Nickname
     pub fn setNickname(&mut self, newNickname: String) -> Result<bool, String> {
 
         // First we check if the nickname is over 11 chars
-        if newNickname.len() > 10 {
+        if newNickname.chars().count() > 10 {
             return Err(formatError(format!("Length of nickname \"{}\" is over 10 characters.", newNickname)));
         }
+        // Pokemon from Japanese saves have kana names
+        textEncode(&newNickname).or_else(|error| kanaEncode(&newNickname).map_err(|_| error))?;
 
         // Now that the check is complete, we change the nickname
         self.nickname = newNickname;
+        self.encodedNickname = None;
 
         return Ok(true);
     }
     
     /// Setter Pokemon Level
+    /// 
+    /// The stats are recalculated for the new level, as the game does when a Pokemon levels up.
     pub fn setLevel(&mut self, newLevel:i8) -> Result<bool, String> {
         
         // First we check that the level is not over 100
@@ -148,12 +295,47 @@ impl Pokemon {
         }
 
         // Now that the checks are complete, we can set the new level
+        // The experience is set to the start of the level, so that the game doesn't change it back
         self.level = newLevel;
+        self.exp = self.getGrowthRate().expForLevel(newLevel);
+        self.updateStatsForLevel();
 
         // And return an Ok()
         return Ok(true);
     }
 
+    /// Setter for Pokemon experience
+    /// 
+    /// The level is changed to match the new experience, and the stats are recalculated for it.
+    pub fn setExp(&mut self, newExp: u32) -> Result<bool, String> {
+        let maxExp = self.getGrowthRate().expForLevel(MAX_LEVEL);
+
+        if newExp > maxExp {
+            return Err(formatError(format!("Value of new EXP \"{}\" is over allowed maximum {}", newExp, maxExp)));
+        }
+
+        self.exp = newExp;
+        self.level = self.getGrowthRate().levelForExp(newExp);
+        self.updateStatsForLevel();
+
+        return Ok(true);
+    }
+
+    /// Setter for Pokemon status condition
+    /// 
+    /// A fainted Pokemon has its status removed by the game, so it can only be Healthy.
+    pub fn setStatus(&mut self, newStatus: StatusCondition) -> Result<bool, String> {
+        newStatus.check()?;
+
+        if self.hp == 0 && newStatus != StatusCondition::Healthy {
+            return Err(formatError(format!("{} has fainted, so can't be given the status \"{}\"", self.nickname, newStatus.getName())));
+        }
+
+        self.status = newStatus.getByte();
+
+        return Ok(true);
+    }
+
     /// Setter for Pokemon OT ID
     /// 
     /// No validation is required in the function as the
@@ -168,17 +350,40 @@ impl Pokemon {
     pub fn setOTN(&mut self, newOTN:String) -> Result<bool, String>{
         
         // First we check that the length is correct
-        if newOTN.len() > 10 {
+        if newOTN.chars().count() > 10 {
             return Err(formatError(format!("OTN \"{}\" is over 7 characters.", newOTN)));
         }
+        textEncode(&newOTN).or_else(|error| kanaEncode(&newOTN).map_err(|_| error))?;
 
         // Now that we've checked the length, we set the name
         self.otn = newOTN;
+        self.encodedOTN = None;
 
         // and return an Ok
         return Ok(true);
     }
 
+    /// Keeps the names as they were read from the save, so that they are written back unchanged
+    pub fn keepEncodedNames(&mut self, nickname: [i16;11], otn: [i16;11]) {
+        self.encodedNickname = Some(nickname);
+        self.encodedOTN = Some(otn);
+    }
+
+    /// Replaces the Pokemon's stats with the calculated ones, as the game does when it leaves the PC
+    pub fn recalculateStats(&mut self) {
+        self.stats = self.calculateStats();
+    }
+
+    /// Recalculates the stats after the level changes, keeping the current HP under the new maximum
+    fn updateStatsForLevel(&mut self) {
+        self.recalculateStats();
+
+        let maxHP = *self.stats.getHP() as i16;
+        if self.hp > maxHP {
+            self.hp = maxHP;
+        }
+    }
+
     // SETTERS FOR EVs
 
     /// Setter for Pokemon HP EV
@@ -220,7 +425,8 @@ impl Pokemon {
 
     /// Setter for Pokemon HP IV
     /// 
-    /// This is an abstraction for pokemonStats::IVs::setHP()
+    /// This is an abstraction for pokemonStats::IVs::setHP(), which always fails
+    /// as the HP IV is derived from the other four
     pub fn setIV_HP(&mut self, newHP: u16) -> Result<bool, String> {
         return self.ivs.setHP(newHP);
     }
@@ -273,6 +479,18 @@ mod basicPkmnTests {
         assert_eq!(stringPokemon, actualString);
     }
 
+    #[test]
+    fn recalculateStats_Correct() {
+        // Test Pokemon is a Bulbasaur at level 10, with no IVs or EVs and its stats left as 0
+        let mut testPokemon = Pokemon::new();
+        assert_eq!(testPokemon.hasCalculatedStats(), false);
+
+        testPokemon.recalculateStats();
+
+        assert_eq!(testPokemon.getStats(), &Stats::set([29, 14, 14, 14, 18]));
+        assert!(testPokemon.hasCalculatedStats());
+    }
+
     #[test]
     fn setNickname_Correct() {
         let mut testPokemon = Pokemon::new();
@@ -299,6 +517,17 @@ mod basicPkmnTests {
 
     }
 
+    #[test]
+    fn setNickname_IncorrectChar() {
+        let mut testPokemon = Pokemon::new();
+
+        let nicknameResult = testPokemon.setNickname(String::from("Jim#saur"));
+
+        assert!(nicknameResult.is_err());
+        assert_eq!(nicknameResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Character \"#\" in \"Jim#saur\" can't be used in Gen 1 text");
+        assert_ne!(testPokemon.getNickname(), "Jim#saur");
+    }
+
     #[test]
     fn setLevel_Correct() {
         // Test Pokemon
@@ -340,6 +569,117 @@ mod basicPkmnTests {
         assert_eq!(levelChangeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Value of new level \"0\" is under allowed minimum 1");
     }
 
+    #[test]
+    fn setLevel_SetsExp() {
+        // Bulbasaur is Medium Slow
+        let mut testPokemon = Pokemon::new();
+
+        testPokemon.setLevel(100).unwrap();
+
+        assert_eq!(testPokemon.getExp(), &1_059_860);
+        assert!(testPokemon.checkExp().is_ok());
+    }
+
+    #[test]
+    fn setExp_Correct() {
+        let mut testPokemon = Pokemon::new();
+
+        // Just short of level 6, which needs 179 EXP
+        let expResult = testPokemon.setExp(178);
+
+        assert_eq!(expResult.unwrap(), true);
+        assert_eq!(testPokemon.getLevel(), &5);
+        assert_eq!(testPokemon.getExp(), &178);
+    }
+
+    #[test]
+    fn setLevel_RecalculatesStats() {
+        let mut testPokemon = Pokemon::new();
+
+        testPokemon.setLevel(5).unwrap();
+
+        assert!(testPokemon.hasCalculatedStats());
+        assert_eq!(testPokemon.getStats(), &Stats::set([19, 9, 9, 9, 11]));
+        // The Pokemon had 100 HP, which is over its new maximum
+        assert_eq!(testPokemon.getHP(), &19);
+
+        testPokemon.setExp(560).unwrap();
+
+        assert!(testPokemon.hasCalculatedStats());
+        assert_eq!(testPokemon.getStats(), &Stats::set([29, 14, 14, 14, 18]));
+        assert_eq!(testPokemon.getHP(), &19);
+    }
+
+    #[test]
+    fn setExp_IncorrectOver() {
+        let mut testPokemon = Pokemon::new();
+
+        let expResult = testPokemon.setExp(1_059_861);
+
+        assert_eq!(expResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Value of new EXP \"1059861\" is over allowed maximum 1059860");
+        assert_eq!(testPokemon.getExp(), &560);
+    }
+
+    #[test]
+    fn checkExp_Mismatch() {
+        let mut testPokemon = Pokemon::new();
+        assert!(testPokemon.checkExp().is_ok());
+
+        // Changing the level directly leaves the old experience behind
+        testPokemon.level = 20;
+
+        assert_eq!(testPokemon.checkExp().unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Bobsaur is level 20, but has 560 EXP, which is level 10");
+    }
+
+    #[test]
+    fn setStatus_Correct() {
+        let mut testPokemon = Pokemon::new();
+
+        assert_eq!(testPokemon.setStatus(StatusCondition::Asleep(3)).unwrap(), true);
+        assert_eq!(testPokemon.getStatus(), StatusCondition::Asleep(3));
+
+        // Setting a new status replaces the old one
+        testPokemon.setStatus(StatusCondition::Burned).unwrap();
+        assert_eq!(testPokemon.getStatus(), StatusCondition::Burned);
+    }
+
+    #[test]
+    fn checkStatus_MultipleStatuses() {
+        let mut testPokemon = Pokemon::new();
+        assert!(testPokemon.checkStatus().is_ok());
+
+        // Poisoned and paralyzed at once, which the game shows as PSN
+        testPokemon.status = 0x48;
+
+        assert_eq!(testPokemon.getStatus(), StatusCondition::Poisoned);
+        assert_eq!(testPokemon.checkStatus().unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Bobsaur has the status byte 0x48, which is more than one status");
+
+        testPokemon.setStatus(StatusCondition::Frozen).unwrap();
+        assert_eq!(testPokemon.getStatusByte(), &0x20);
+        assert!(testPokemon.checkStatus().is_ok());
+    }
+
+    #[test]
+    fn setStatus_IncorrectSleep() {
+        let mut testPokemon = Pokemon::new();
+
+        let statusResult = testPokemon.setStatus(StatusCondition::Asleep(9));
+
+        assert_eq!(statusResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Sleep counter \"9\" is outside of the allowed range 1-7");
+        assert_eq!(testPokemon.getStatus(), StatusCondition::Healthy);
+    }
+
+    #[test]
+    fn setStatus_IncorrectFainted() {
+        let mut testPokemon = Pokemon::new();
+        testPokemon.hp = 0;
+
+        let statusResult = testPokemon.setStatus(StatusCondition::Poisoned);
+
+        assert_eq!(statusResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Bobsaur has fainted, so can't be given the status \"PSN\"");
+        assert!(testPokemon.setStatus(StatusCondition::Healthy).is_ok());
+    }
+
     #[test]
     fn setOTID_Correct() {
 
@@ -455,7 +795,7 @@ mod IVTests {
     use super::*;
 
     #[test]
-    fn setIV_HP_Correct() {
+    fn setIV_HP_Derived() {
         let mut testPkmn = Pokemon::new();
 
         // Boundary value
@@ -463,8 +803,8 @@ mod IVTests {
 
         let changeHPREsult = testPkmn.setIV_HP(newHP); 
 
-        assert!(changeHPREsult.is_ok());
-        assert_eq!(changeHPREsult.unwrap(), true);
+        assert!(changeHPREsult.is_err());
+        assert_eq!(changeHPREsult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: HP IV can't be set to \"15\", as it is derived from the ATK, DEF, SPD and SPC IVs");
 
     }
 
@@ -0,0 +1,96 @@
+/// The highest level a Pokemon can reach
+pub const MAX_LEVEL: i8 = 100;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/// How much experience a species needs to level up.
+///
+/// The values are the ones the games use in the species data.
+/// Slightly Fast and Slightly Slow exist in Gen 1, but no species uses them.
+/// More Info [here](https://bulbapedia.bulbagarden.net/wiki/Experience#Relation_to_level)
+pub enum GrowthRate {
+    MediumFast      = 0,
+    SlightlyFast    = 1,
+    SlightlySlow    = 2,
+    MediumSlow      = 3,
+    Fast            = 4,
+    Slow            = 5,
+}
+impl GrowthRate {
+    pub fn get(index: i16) -> Result<GrowthRate, String> {
+        return match index {
+            0   => Ok(GrowthRate::MediumFast),
+            1   => Ok(GrowthRate::SlightlyFast),
+            2   => Ok(GrowthRate::SlightlySlow),
+            3   => Ok(GrowthRate::MediumSlow),
+            4   => Ok(GrowthRate::Fast),
+            5   => Ok(GrowthRate::Slow),
+            _   => Err(format!("Growth rate with ID {index} not found."))
+        };
+    }
+
+    /// Returns the total experience needed to reach a level.
+    ///
+    /// The games work this out with whole numbers, so the divisions round down.
+    /// Medium Slow would be negative at level 1, so it is kept at 0.
+    pub fn expForLevel(&self, level: i8) -> u32 {
+        let n = level as i64;
+        let cube = n * n * n;
+
+        let exp = match self {
+            GrowthRate::MediumFast      => cube,
+            GrowthRate::SlightlyFast    => (3 * cube / 4) + (10 * n * n) - 30,
+            GrowthRate::SlightlySlow    => (3 * cube / 4) + (20 * n * n) - 70,
+            GrowthRate::MediumSlow      => (6 * cube / 5) - (15 * n * n) + (100 * n) - 140,
+            GrowthRate::Fast            => 4 * cube / 5,
+            GrowthRate::Slow            => 5 * cube / 4,
+        };
+
+        return exp.max(0) as u32;
+    }
+
+    /// Returns the level a Pokemon with the given experience is at
+    pub fn levelForExp(&self, exp: u32) -> i8 {
+        let mut level: i8 = 1;
+
+        while level < MAX_LEVEL && self.expForLevel(level + 1) <= exp {
+            level += 1;
+        }
+
+        return level;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_Incorrect() {
+        assert_eq!(GrowthRate::get(6).unwrap_err(), "Growth rate with ID 6 not found.");
+    }
+
+    #[test]
+    fn expForLevel_MaxLevel() {
+        assert_eq!(GrowthRate::MediumFast.expForLevel(100), 1_000_000);
+        assert_eq!(GrowthRate::MediumSlow.expForLevel(100), 1_059_860);
+        assert_eq!(GrowthRate::Fast.expForLevel(100), 800_000);
+        assert_eq!(GrowthRate::Slow.expForLevel(100), 1_250_000);
+    }
+
+    #[test]
+    fn expForLevel_MediumSlowLow() {
+        // The formula is negative at level 1
+        assert_eq!(GrowthRate::MediumSlow.expForLevel(1), 0);
+        assert_eq!(GrowthRate::MediumSlow.expForLevel(2), 9);
+        assert_eq!(GrowthRate::MediumSlow.expForLevel(5), 135);
+    }
+
+    #[test]
+    fn levelForExp_Correct() {
+        assert_eq!(GrowthRate::MediumFast.levelForExp(0), 1);
+        assert_eq!(GrowthRate::MediumFast.levelForExp(124), 4);
+        assert_eq!(GrowthRate::MediumFast.levelForExp(125), 5);
+        // Experience over the maximum stays at level 100
+        assert_eq!(GrowthRate::Slow.levelForExp(2_000_000), 100);
+    }
+}
@@ -1,5 +1,7 @@
 use std::fs;
 use super::pokemonType::Type;
+use super::pokemonStats::Stats;
+use super::pokemonGrowthRate::GrowthRate;
 
 #[derive(Debug)]
 /**
@@ -8,6 +10,11 @@ use super::pokemonType::Type;
  * pokedex: The number of the pokemon in the pokedex
  * name: The string name of the species
  * typing: an array holding the two types of a species
+ * genderRatio: the Gen 2 gender ratio, which Gen 1 species don't have
+ * baseStats: the Gen 1 base stats, which Gen 2 species don't have yet
+ * growthRate: how much experience the species needs to level up, which Gen 2 species don't have yet
+ * gen1Types: the type IDs Gen 1 stores in each Pokemon, before Steel and Fairy existed
+ * catchRate: the Gen 1 catch rate, which is also stored in each Pokemon
  */
 pub struct Species {
     index: i16,
@@ -15,6 +22,11 @@ pub struct Species {
     // TODO: Get a Getter
     pub name: String,
     typing: [Type;2],
+    genderRatio: Option<u8>,
+    baseStats: Option<Stats>,
+    growthRate: Option<GrowthRate>,
+    gen1Types: Option<[u8;2]>,
+    catchRate: Option<u8>,
 }
 impl Species {
     pub fn parse(index: i16) -> Result<Species, String> {
@@ -42,7 +54,47 @@ impl Species {
                                 Type::get(types[1].parse::<i16>().unwrap())
                                 ];
 
-        return Ok(Species{index,pokedex,name,typing});
+        let baseStatList: Vec<u16> = info[4].trim_matches('{').trim_matches('}').split(',').map(|stat| stat.parse::<u16>().unwrap()).collect();
+        let baseStats = Stats::set([baseStatList[0], baseStatList[1], baseStatList[2], baseStatList[3], baseStatList[4]]);
+
+        let growthRate = GrowthRate::get(info[5].parse::<i16>().unwrap())?;
+
+        let gen1TypeList: Vec<u8> = info[6].trim_matches('{').trim_matches('}').split(',').map(|id| id.parse::<u8>().unwrap()).collect();
+        let catchRate = info[7].parse::<u8>().unwrap();
+
+        return Ok(Species{index,pokedex,name,typing,genderRatio:None,baseStats:Some(baseStats),growthRate:Some(growthRate),
+                            gen1Types:Some([gen1TypeList[0], gen1TypeList[1]]),catchRate:Some(catchRate)});
+    }
+
+    /// Constructor for a species that isn't in the species list, such as a glitch Pokemon
+    pub fn unknown(index: i16) -> Species {
+        return Species{index,pokedex:0,name:String::from("Unknown"),typing:[Type::Null, Type::Null],genderRatio:None,baseStats:None,growthRate:None,
+                            gen1Types:None,catchRate:None};
+    }
+
+    /// Constructor for a Species from a Gen 2 save.
+    /// 
+    /// Gen 2 stores Pokemon by their Pokedex number, so the index and the Pokedex are the same.
+    pub fn parseGen2(index: i16) -> Result<Species, String> {
+        let speciesFile = fs::read_to_string("./data/species2.pkmn").unwrap();
+        let dexIndex = format!("{:03} ",index);
+
+        let parsedSpecies = match speciesFile.lines().find(|line| line.starts_with(&dexIndex)) {
+            Some(line)  => line,
+            None        => return Err(format!("Gen 2 Species with ID {index} not found."))
+        };
+
+        let info: Vec<&str> = parsedSpecies.split(" ").collect();
+        let name = info[1].to_string();
+
+        let types: Vec<&str> = info[2].trim_matches('{').trim_matches('}').split(',').collect();
+        let typing: [Type;2] = [
+                                Type::get(types[0].parse::<i16>().unwrap()), 
+                                Type::get(types[1].parse::<i16>().unwrap())
+                                ];
+        let genderRatio = info[3].parse::<u8>().unwrap();
+
+        return Ok(Species{index,pokedex:index,name,typing,genderRatio:Some(genderRatio),baseStats:None,growthRate:None,gen1Types:None,catchRate:None});
     }
 
     pub fn getIndex(&self) -> &i16 {
@@ -60,6 +112,39 @@ impl Species {
     pub fn getTyping(&self) -> &[Type;2] {
         return &self.typing;
     }
+
+    /// Returns the Gen 1 base stats, which are used to calculate a Pokemon's stats.
+    /// 
+    /// Gen 2 species split the Special, so this is `None` for them.
+    pub fn getBaseStats(&self) -> &Option<Stats> {
+        return &self.baseStats;
+    }
+
+    /// Returns the growth rate, which Gen 2 species don't have yet
+    pub fn getGrowthRate(&self) -> &Option<GrowthRate> {
+        return &self.growthRate;
+    }
+
+    /// Returns the two type IDs a Gen 1 Pokemon of this species stores in its data.
+    /// 
+    /// These are the Gen 1 types, so Clefairy is Normal and Magnemite is only Electric.
+    /// Species with one type have it twice. Gen 2 species don't have these.
+    pub fn getGen1Types(&self) -> &Option<[u8;2]> {
+        return &self.gen1Types;
+    }
+
+    /// Returns the Gen 1 catch rate, which Gen 2 species don't have
+    pub fn getCatchRate(&self) -> &Option<u8> {
+        return &self.catchRate;
+    }
+
+    /// Returns the Gen 2 gender ratio, where a DV value at or under it is female.
+    /// 
+    /// 0 is male only, 254 female only and 255 genderless.
+    /// Gen 1 species have no gender, so this is `None` for them.
+    pub fn getGenderRatio(&self) -> &Option<u8> {
+        return &self.genderRatio;
+    }
 }
 
 #[cfg(test)]
@@ -77,7 +162,12 @@ mod tests {
             index: 0x99, 
             pokedex: 1, 
             name: "Bulbasaur".to_string(), 
-            typing: [Type::Grass, Type::Poison]};
+            typing: [Type::Grass, Type::Poison],
+            genderRatio: None,
+            baseStats: Some(Stats::set([45, 49, 49, 45, 65])),
+            growthRate: Some(GrowthRate::MediumSlow),
+            gen1Types: Some([0x16, 0x03]),
+            catchRate: Some(45)};
 
         // Assert Name
         assert_eq!(&correctSpecies.getName(), &parsedSpecies.getName());
@@ -85,6 +175,24 @@ mod tests {
         assert_eq!(&correctSpecies.getIndex(), &parsedSpecies.getIndex());
         // Assert Pokedex
         assert_eq!(&correctSpecies.getPokedex(), &parsedSpecies.getPokedex());
+        // Assert Base Stats
+        assert_eq!(correctSpecies.getBaseStats(), parsedSpecies.getBaseStats());
+        // Assert Growth Rate
+        assert_eq!(correctSpecies.getGrowthRate(), parsedSpecies.getGrowthRate());
+        // Assert Gen 1 Types and Catch Rate
+        assert_eq!(correctSpecies.getGen1Types(), parsedSpecies.getGen1Types());
+        assert_eq!(correctSpecies.getCatchRate(), parsedSpecies.getCatchRate());
+    }
+
+    #[test]
+    fn parse_testGen1Types() {
+        // Magnemite was only Electric before Steel was added
+        let parsedSpecies: Species = Species::parse(0xAD).unwrap();
+
+        assert_eq!(parsedSpecies.getName(), "Magnemite");
+        assert_eq!(parsedSpecies.getTyping(), &[Type::Electric, Type::Steel]);
+        assert_eq!(parsedSpecies.getGen1Types(), &Some([0x17, 0x17]));
+        assert_eq!(parsedSpecies.getCatchRate(), &Some(190));
     }
 
     #[test]
@@ -97,4 +205,30 @@ mod tests {
         assert_eq!(Species::parse(incorrectID).unwrap_err(), "Species with ID 0x00 not found.");
     }
 
+    #[test]
+    fn parseGen2_testCorrectPokemon() {
+        let parsedSpecies: Species = Species::parseGen2(196).unwrap();
+
+        assert_eq!(parsedSpecies.getName(), "Espeon");
+        assert_eq!(parsedSpecies.getIndex(), &196);
+        assert_eq!(parsedSpecies.getPokedex(), &196);
+        assert_eq!(parsedSpecies.getTyping(), &[Type::Psychic, Type::Null]);
+        assert_eq!(parsedSpecies.getGenderRatio(), &Some(31));
+    }
+
+    #[test]
+    fn parseGen2_testGen1Pokemon() {
+        // Gen 1 Pokemon are stored by their Pokedex number in Gen 2
+        let parsedSpecies: Species = Species::parseGen2(1).unwrap();
+
+        assert_eq!(parsedSpecies.getName(), "Bulbasaur");
+        assert_eq!(parsedSpecies.getTyping(), &[Type::Grass, Type::Poison]);
+    }
+
+    #[test]
+    fn parseGen2_testIncorrectIndex() {
+        assert_eq!(Species::parseGen2(252).unwrap_err(), "Gen 2 Species with ID 252 not found.");
+        assert!(Species::parseGen2(0).is_err());
+    }
+
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use super::super::utils::formatError;
+use super::pokemonSpecies::Species;
 
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Stats {
     hp: u16,
     atk: u16,
@@ -68,6 +69,39 @@ impl Stats {
         }
     }
 
+    /// Calculates a Pokemon's stats with the Gen 1 formula, from its species base stats,
+    /// level, Determinant Values and Stat Experience.
+    /// 
+    /// This is needed for boxed Pokemon, as the box format doesn't store stats.
+    /// More Info [here](https://bulbapedia.bulbagarden.net/wiki/Stat#Generations_I_and_II)
+    pub fn calculate(species: &Species, level: i8, ivs: &IVs, evs: &EVs) -> Result<Stats, String> {
+        let base = match species.getBaseStats() {
+            Some(base)  => base,
+            None        => return Err(format!("Species {} has no Gen 1 base stats.", species.getName()))
+        };
+        let level = level as u16;
+
+        return Ok(Stats {
+            hp:     Self::calculateStat(base.hp, ivs.hp, evs.hp, level) + level + 10,
+            atk:    Self::calculateStat(base.atk, ivs.atk, evs.atk, level) + 5,
+            def:    Self::calculateStat(base.def, ivs.def, evs.def, level) + 5,
+            spd:    Self::calculateStat(base.spd, ivs.spd, evs.spd, level) + 5,
+            spc:    Self::calculateStat(base.spc, ivs.spc, evs.spc, level) + 5
+        });
+    }
+
+    /// The part of the stat formula that is the same for every stat
+    fn calculateStat(base: u16, iv: u16, ev: u16, level: u16) -> u16 {
+        // The game uses the smallest whole root that squares to at least the Stat Experience,
+        // which is at most 255
+        let mut evRoot: u32 = 0;
+        while evRoot < 255 && evRoot * evRoot < ev as u32 {
+            evRoot += 1;
+        }
+
+        return ((((base + iv) * 2) as u32 + evRoot / 4) * level as u32 / 100) as u16;
+    }
+
     // Currently no Setters are planned.
                     
 }
@@ -75,6 +109,42 @@ impl Stats {
 #[cfg(test)]
 mod statsTests {
 
+    use super::*;
+
+    #[test]
+    fn calculate_NoStatExp() {
+        // A level 100 Mewtwo with perfect DVs and no Stat Experience
+        let species = Species::parse(0x83).unwrap();
+        let stats = Stats::calculate(&species, 100, &IVs::setAll([15;5]), &EVs::new()).unwrap();
+
+        assert_eq!(stats, Stats::set([352, 255, 215, 295, 343]));
+    }
+
+    #[test]
+    fn calculate_MaxStatExp() {
+        // Stat Experience of 65535 adds 63 to each stat at level 100
+        let species = Species::parse(0x83).unwrap();
+        let stats = Stats::calculate(&species, 100, &IVs::setAll([15;5]), &EVs::setAll([65535;5])).unwrap();
+
+        assert_eq!(stats, Stats::set([415, 318, 278, 358, 406]));
+    }
+
+    #[test]
+    fn calculate_StatExpRoot() {
+        // The root is rounded up, so 101 Stat Experience counts as 11 (11 / 4 = 2)
+        let species = Species::parse(0x83).unwrap();
+        let stats = Stats::calculate(&species, 100, &IVs::new(), &EVs::setAll([101, 100, 0, 0, 0])).unwrap();
+
+        assert_eq!(*stats.getHP(), 212 + 2 + 110);
+        assert_eq!(*stats.getATK(), 220 + 2 + 5);
+    }
+
+    #[test]
+    fn calculate_Gen2Species() {
+        let species = Species::parseGen2(196).unwrap();
+
+        assert_eq!(Stats::calculate(&species, 5, &IVs::new(), &EVs::new()).unwrap_err(), "Species Espeon has no Gen 1 base stats.");
+    }
 }
 
 #[derive(Debug)]
@@ -146,7 +216,8 @@ impl IVs {
 
     /// Setter for HP IV
     /// 
-    /// IV's can only be values 0-15
+    /// The HP IV isn't stored, it is made from the lowest bit of the other four IVs,
+    /// so it can only be changed through them
     pub fn setHP(&mut self, newHP: u16) -> Result<bool, String> {
         
         // First we check if the value is over 15
@@ -154,12 +225,12 @@ impl IVs {
             return Err(formatError(format!("HP IV value is \"{}\", which is over max value 15", newHP)));
         }
 
-        // If it's not, we set the new HP
-        self.hp = newHP;
-
-        // And return an Ok
-        return Ok(true);
+        return Err(formatError(format!("HP IV can't be set to \"{}\", as it is derived from the ATK, DEF, SPD and SPC IVs", newHP)));
+    }
 
+    /// Rebuilds the HP IV from the lowest bit of the ATK, DEF, SPD and SPC IVs
+    fn deriveHP(&mut self) {
+        self.hp = ((self.atk & 1) << 3) | ((self.def & 1) << 2) | ((self.spd & 1) << 1) | (self.spc & 1);
     }
 
     /// Setter for Attack IV
@@ -172,8 +243,9 @@ impl IVs {
             return Err(formatError(format!("ATK IV value is \"{}\", which is over max value 15", newATK)));
         }
 
-        // If it's not, we set the new HP
+        // If it's not, we set the new ATK and the HP that comes from it
         self.atk = newATK;
+        self.deriveHP();
 
         // And return an Ok
         return Ok(true);
@@ -189,8 +261,9 @@ impl IVs {
             return Err(formatError(format!("DEF IV value is \"{}\", which is over max value 15", newDEF)));
         }
 
-        // If it's not, we set the new HP
+        // If it's not, we set the new DEF and the HP that comes from it
         self.def = newDEF;
+        self.deriveHP();
 
         // And return an Ok
         return Ok(true);
@@ -206,8 +279,9 @@ impl IVs {
             return Err(formatError(format!("SPD IV value is \"{}\", which is over max value 15", newSPD)));
         }
 
-        // If it's not, we set the new HP
+        // If it's not, we set the new SPD and the HP that comes from it
         self.spd = newSPD;
+        self.deriveHP();
 
         // And return an Ok
         return Ok(true);
@@ -222,8 +296,9 @@ impl IVs {
             return Err(formatError(format!("SPC IV value is \"{}\", which is over max value 15", newSPC)));
         }
 
-        // If it's not, we set the new HP
-        self.spd = newSPC;
+        // If it's not, we set the new SPC and the HP that comes from it
+        self.spc = newSPC;
+        self.deriveHP();
 
         // And return an Ok
         return Ok(true);    
@@ -237,7 +312,7 @@ mod IVTests {
     use super::*;
 
     #[test]
-    fn setHP_Correct() {
+    fn setHP_Derived() {
         let mut testIVs = IVs::new();
 
         // Boundary value
@@ -245,9 +320,28 @@ mod IVTests {
 
         let changeHPREsult = testIVs.setHP(newHP); 
 
-        assert!(changeHPREsult.is_ok());
-        assert_eq!(changeHPREsult.unwrap(), true);
+        assert!(changeHPREsult.is_err());
+        assert_eq!(changeHPREsult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: HP IV can't be set to \"15\", as it is derived from the ATK, DEF, SPD and SPC IVs");
+        assert_eq!(testIVs.getHP(), &0);
+
+    }
+
+    #[test]
+    fn setIVs_DerivesHP() {
+        let mut testIVs = IVs::new();
 
+        // Each odd IV sets one bit of the HP IV, from ATK down to SPC
+        testIVs.setATK(9).unwrap();
+        assert_eq!(testIVs.getHP(), &8);
+        testIVs.setDEF(3).unwrap();
+        testIVs.setSPD(1).unwrap();
+        testIVs.setSPC(15).unwrap();
+        assert_eq!(testIVs.getHP(), &15);
+        assert_eq!(testIVs.getSPD(), &1);
+        assert_eq!(testIVs.getSPC(), &15);
+
+        testIVs.setDEF(2).unwrap();
+        assert_eq!(testIVs.getHP(), &11);
     }
 
     #[test]
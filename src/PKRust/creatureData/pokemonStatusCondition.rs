@@ -1,4 +1,137 @@
-#[derive(Debug)]
-enum StatusCondition {
+use super::super::utils::formatError;
 
+/// The most turns a Pokemon can be put to sleep for
+pub const MAX_SLEEP_TURNS: u8 = 7;
+
+// Bits of the status byte
+const SLEEP_MASK:   u8 = 0b0000_0111;
+const POISON_BIT:   u8 = 0b0000_1000;
+const BURN_BIT:     u8 = 0b0001_0000;
+const FREEZE_BIT:   u8 = 0b0010_0000;
+const PARALYSIS_BIT:u8 = 0b0100_0000;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/// A Pokemon's non-volatile status, which stays after battle.
+///
+/// The game only gives a Pokemon one of these at a time, but edited saves can set more than one.
+/// More Info [here](https://bulbapedia.bulbagarden.net/wiki/Status_condition)
+pub enum StatusCondition {
+    Healthy,
+    /// Asleep, with the amount of turns left (1-7)
+    Asleep(u8),
+    Poisoned,
+    Burned,
+    Frozen,
+    Paralyzed,
+}
+impl StatusCondition {
+    /// Constructor for a status, from the status byte of a Pokemon
+    ///
+    /// Bits 0-2 are the sleep counter, and bits 3-6 are poison, burn, freeze and paralysis.
+    /// If more than one is set, the one the game shows is used. It checks poison, burn, freeze
+    /// and paralysis in that order, and sleep last.
+    pub fn get(status: u8) -> StatusCondition {
+        if status & POISON_BIT != 0 {
+            return StatusCondition::Poisoned;
+        } else if status & BURN_BIT != 0 {
+            return StatusCondition::Burned;
+        } else if status & FREEZE_BIT != 0 {
+            return StatusCondition::Frozen;
+        } else if status & PARALYSIS_BIT != 0 {
+            return StatusCondition::Paralyzed;
+        } else if status & SLEEP_MASK != 0 {
+            return StatusCondition::Asleep(status & SLEEP_MASK);
+        }
+
+        return StatusCondition::Healthy;
+    }
+
+    /// Returns whether a status byte holds at most one status, and no unused bits
+    pub fn isLegal(status: u8) -> bool {
+        return Self::get(status).getByte() == status;
+    }
+
+    /// Returns the status byte, as it is stored in the save
+    pub fn getByte(&self) -> u8 {
+        return match self {
+            StatusCondition::Healthy        => 0,
+            StatusCondition::Asleep(turns)  => turns & SLEEP_MASK,
+            StatusCondition::Poisoned       => POISON_BIT,
+            StatusCondition::Burned         => BURN_BIT,
+            StatusCondition::Frozen         => FREEZE_BIT,
+            StatusCondition::Paralyzed      => PARALYSIS_BIT,
+        };
+    }
+
+    /// Returns the short name the game shows for a status
+    pub fn getName(&self) -> String {
+        return String::from(match self {
+            StatusCondition::Healthy    => "OK",
+            StatusCondition::Asleep(_)  => "SLP",
+            StatusCondition::Poisoned   => "PSN",
+            StatusCondition::Burned     => "BRN",
+            StatusCondition::Frozen     => "FRZ",
+            StatusCondition::Paralyzed  => "PAR",
+        });
+    }
+
+    /// Checks that a status can be stored in the save
+    ///
+    /// Sleep only has 3 bits for its counter, and a Pokemon with 0 turns left is awake.
+    pub fn check(&self) -> Result<bool, String> {
+        if let StatusCondition::Asleep(turns) = self {
+            if *turns == 0 || *turns > MAX_SLEEP_TURNS {
+                return Err(formatError(format!("Sleep counter \"{}\" is outside of the allowed range 1-{}", turns, MAX_SLEEP_TURNS)));
+            }
+        }
+
+        return Ok(true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_Correct() {
+        assert_eq!(StatusCondition::get(0x00), StatusCondition::Healthy);
+        assert_eq!(StatusCondition::get(0x03), StatusCondition::Asleep(3));
+        assert_eq!(StatusCondition::get(0x08), StatusCondition::Poisoned);
+        assert_eq!(StatusCondition::get(0x10), StatusCondition::Burned);
+        assert_eq!(StatusCondition::get(0x20), StatusCondition::Frozen);
+        assert_eq!(StatusCondition::get(0x40), StatusCondition::Paralyzed);
+    }
+
+    #[test]
+    fn get_MultipleStatuses() {
+        // The game shows poison, burn, freeze and paralysis before sleep
+        assert_eq!(StatusCondition::get(0x49), StatusCondition::Poisoned);
+        assert_eq!(StatusCondition::get(0x48), StatusCondition::Poisoned);
+        assert_eq!(StatusCondition::get(0x41), StatusCondition::Paralyzed);
+    }
+
+    #[test]
+    fn isLegal_Correct() {
+        assert!(StatusCondition::isLegal(0x00));
+        assert!(StatusCondition::isLegal(0x05));
+        assert!(StatusCondition::isLegal(0x40));
+        assert!(!StatusCondition::isLegal(0x48));
+        assert!(!StatusCondition::isLegal(0x41));
+        assert!(!StatusCondition::isLegal(0x80));
+    }
+
+    #[test]
+    fn getByte_Correct() {
+        for status in [0x00, 0x07, 0x08, 0x10, 0x20, 0x40] {
+            assert_eq!(StatusCondition::get(status).getByte(), status);
+        }
+    }
+
+    #[test]
+    fn check_IncorrectSleep() {
+        assert!(StatusCondition::Asleep(7).check().is_ok());
+        assert_eq!(StatusCondition::Asleep(8).check().unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Sleep counter \"8\" is outside of the allowed range 1-7");
+        assert!(StatusCondition::Asleep(0).check().is_err());
+    }
 }
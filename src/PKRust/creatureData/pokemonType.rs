@@ -25,6 +25,7 @@ pub enum Type {
 }
 impl Type {
     /// Index is not borrowed as it is used by the resulting Type Object
+    #[allow(clippy::let_and_return)]
     pub fn get(index: i16) -> Type{
         let returnPkmnType = match index {
             0   => Type::Normal   ,
@@ -51,6 +52,54 @@ impl Type {
 
         returnPkmnType
     }
+
+    /// Constructor for a Type from the ID Gen 1 uses for it, such as the type bytes in a Pokemon.
+    /// 
+    /// Gen 1 numbers its types differently, with a gap between the physical and special types.
+    /// Dark, Steel and Fairy don't exist yet, and 0x06 is the unused Bird type.
+    /// More Info [here](https://bulbapedia.bulbagarden.net/wiki/Type#Generation_I)
+    pub fn from_gen1_id(id: u8) -> Result<Type, String> {
+        return match id {
+            0x00    => Ok(Type::Normal),
+            0x01    => Ok(Type::Fighting),
+            0x02    => Ok(Type::Flying),
+            0x03    => Ok(Type::Poison),
+            0x04    => Ok(Type::Ground),
+            0x05    => Ok(Type::Rock),
+            0x07    => Ok(Type::Bug),
+            0x08    => Ok(Type::Ghost),
+            0x14    => Ok(Type::Fire),
+            0x15    => Ok(Type::Water),
+            0x16    => Ok(Type::Grass),
+            0x17    => Ok(Type::Electric),
+            0x18    => Ok(Type::Psychic),
+            0x19    => Ok(Type::Ice),
+            0x1A    => Ok(Type::Dragon),
+            _       => Err(format!("Gen 1 Type with ID 0x{:02X} not found.", id))
+        };
+    }
+
+    /// Returns the ID Gen 1 uses for a Type, or an error for types that were added later
+    pub fn to_gen1_id(&self) -> Result<u8, String> {
+        return match self {
+            Type::Normal    => Ok(0x00),
+            Type::Fighting  => Ok(0x01),
+            Type::Flying    => Ok(0x02),
+            Type::Poison    => Ok(0x03),
+            Type::Ground    => Ok(0x04),
+            Type::Rock      => Ok(0x05),
+            Type::Bug       => Ok(0x07),
+            Type::Ghost     => Ok(0x08),
+            Type::Fire      => Ok(0x14),
+            Type::Water     => Ok(0x15),
+            Type::Grass     => Ok(0x16),
+            Type::Electric  => Ok(0x17),
+            Type::Psychic   => Ok(0x18),
+            Type::Ice       => Ok(0x19),
+            Type::Dragon    => Ok(0x1A),
+            _               => Err(format!("{:?} Type doesn't exist in Gen 1.", self))
+        };
+    }
 }
 
 #[cfg(test)]
@@ -72,4 +121,34 @@ pub mod tests {
 
         assert_eq!(testType, correctType);
     }
+
+    #[test]
+    fn from_gen1_id_Correct() {
+        assert_eq!(Type::from_gen1_id(0x00).unwrap(), Type::Normal);
+        assert_eq!(Type::from_gen1_id(0x08).unwrap(), Type::Ghost);
+        assert_eq!(Type::from_gen1_id(0x14).unwrap(), Type::Fire);
+        assert_eq!(Type::from_gen1_id(0x1A).unwrap(), Type::Dragon);
+    }
+
+    #[test]
+    fn from_gen1_id_Incorrect() {
+        // The unused Bird type and the gap before Fire
+        assert_eq!(Type::from_gen1_id(0x06).unwrap_err(), "Gen 1 Type with ID 0x06 not found.");
+        assert!(Type::from_gen1_id(0x09).is_err());
+        assert!(Type::from_gen1_id(0x1B).is_err());
+    }
+
+    #[test]
+    fn to_gen1_id_RoundTrip() {
+        for id in (0x00..=0x08).chain(0x14..=0x1A).filter(|id| *id != 0x06) {
+            assert_eq!(Type::from_gen1_id(id).unwrap().to_gen1_id().unwrap(), id);
+        }
+    }
+
+    #[test]
+    fn to_gen1_id_LaterTypes() {
+        assert_eq!(Type::Steel.to_gen1_id().unwrap_err(), "Steel Type doesn't exist in Gen 1.");
+        assert!(Type::Dark.to_gen1_id().is_err());
+        assert!(Type::Fairy.to_gen1_id().is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,309 @@
+use std::fs;
+use std::path::PathBuf;
+
+use super::creatureData::pokemonMove::Move;
+use super::creatureData::gen2Pokemon::{Gen2Pokemon, CaughtData};
+use super::addresses::*;
+use super::utils::{textDecode, detectGen2Layout, formatError, TEXT_TERMINATOR};
+use super::utils::container::SaveContainer;
+
+/// Names in Gen 2 are 11 bytes, the same as in international Gen 1 saves
+const GEN2_NAME_LENGTH: usize = 0xB;
+
+/// A save from Gold, Silver or Crystal.
+///
+/// Gen 2 saves use the same character encoding as Gen 1, but a different
+/// checksum and a longer Pokemon structure, so they are loaded on their own.
+/// Only international saves are supported.
+#[derive(Debug)]
+pub struct Gen2Save {
+    trainer: String,
+    rival: String,
+    money: u32,
+    id: u16,
+    party: Vec<Gen2Pokemon>,
+
+    /// The layout of the save, which depends on the version of the game
+    layout: &'static Gen2Layout,
+    /// The format of the file the save was read from
+    container: SaveContainer,
+    /// The original save image
+    data: Vec<u8>,
+}
+
+#[allow(dead_code)]
+impl Gen2Save {
+
+    pub fn load(file: &str) -> Result<Gen2Save, String> {
+
+        let filePathBuf:PathBuf = std::path::PathBuf::from(file);
+
+        let saveFile = match fs::read(filePathBuf) {
+            Ok(result)                => result,
+            Err(error)                  => match error.kind() {
+                std::io::ErrorKind::NotFound   => return Err(formatError(format!("Save \"{}\" does not exist",file))),
+                _                              => return Err(format!("Unexpected Error: {}",error.kind()))
+            }
+        };
+
+        // Gen 2 saves often have a real time clock footer, as the cartridges have a clock
+        let (container, save) = SaveContainer::detect(&saveFile).map_err(formatError)?;
+
+        // Crystal moved the checksum, which is how the version is found
+        let layout = match detectGen2Layout(&save) {
+            Some(layout)    => layout,
+            None            => return Err(formatError(String::from("File does not seem to be a Gen 2 Save File")))
+        };
+
+        let trainer = textDecode(&Self::getEncodedNameFromSave(&save, &layout.nameAddr));
+        let rival = textDecode(&Self::getEncodedNameFromSave(&save, &layout.rivalAddr));
+        let money = Self::getMoneyFromSave(&save, layout);
+        let id = u16::from_be_bytes([save[layout.idAddr], save[layout.idAddr+1]]);
+        let party = Self::getPartyFromSave(&save, layout).map_err(|error| formatError(format!("Party is corrupted: {}", error)))?;
+
+        return Ok(Gen2Save{trainer, rival, money, id, party, layout, container, data: save});
+    }
+
+    pub fn to_string(&self) -> String {
+        return format!("{:?}",self);
+    }
+
+    /// Print the save file data to terminal
+    pub fn print(&self) {
+        println!("\n=== Save Info ===");
+        println!("Game: {}", self.layout.name);
+        println!("Format: {}", self.container.to_string());
+        println!("Name: {}\nPlayer ID: {}\nMoney: {}",self.trainer, self.id, self.money);
+        println!("Rival: {}", self.rival);
+        println!("=================");
+
+        println!("\n=== Party ===");
+        for pokemon in &self.party {
+            println!("{}", pokemon.getDetails());
+        }
+        println!("=================\n");
+    }
+
+    // ========   GETTERS   ========
+
+    /// Getter for the name of the game the save is from
+    pub fn getGameName(&self) -> &str {
+        return self.layout.name;
+    }
+
+    /// Getter for Trainer Name in Save
+    pub fn getTrainerName(&self) -> &String {
+        return &self.trainer;
+    }
+
+    /// Getter for Rival Name in Save
+    pub fn getRivalName(&self) -> &String {
+        return &self.rival;
+    }
+
+    /// Getter for Money in Save
+    pub fn getMoney(&self) -> &u32 {
+        return &self.money;
+    }
+
+    /// Getter for Trainer ID in Save
+    pub fn getTrainerID(&self) -> &u16 {
+        return &self.id;
+    }
+
+    /// Getter for Party Pokemon
+    pub fn getParty(&self) -> &Vec<Gen2Pokemon> {
+        return &self.party;
+    }
+
+    /// Getter for the format of the file the save was read from
+    pub fn getContainer(&self) -> &SaveContainer {
+        return &self.container;
+    }
+
+    /// Returns the save image as it was loaded, in the same file format
+    pub fn to_bytes(&self) -> Vec<u8> {
+        return self.container.wrap(&self.data);
+    }
+
+    // ========   SAVE FILE READING    ========
+
+    /// Retrieves an encoded name, the same way as in Gen 1
+    fn getEncodedNameFromSave(save: &Vec<u8>, currAddr: &usize) -> [i16;11] {
+        let mut name: [i16; 11] = [TEXT_TERMINATOR; 11];
+        for num in 0..GEN2_NAME_LENGTH {
+            name[num] = save[currAddr+num] as i16;
+        }
+        return name;
+    }
+
+    /// Retrieves the amount of money the player has
+    ///
+    /// Unlike Gen 1, money is stored as a plain 3 byte big endian number
+    fn getMoneyFromSave(save: &Vec<u8>, layout: &Gen2Layout) -> u32 {
+        let moneyAddr = layout.moneyAddr;
+
+        return u32::from_be_bytes([0, save[moneyAddr], save[moneyAddr+1], save[moneyAddr+2]]);
+    }
+
+    /// Retrieves the players party
+    fn getPartyFromSave(save: &Vec<u8>, layout: &Gen2Layout) -> Result<Vec<Gen2Pokemon>, String> {
+        let mut party: Vec<Gen2Pokemon> = Vec::new();
+        let partyAddr = layout.partyAddr;
+        // A corrupted count shouldn't make us read past the end of the party
+        let partySize = (save[partyAddr] as usize).min(6);
+
+        for creature in 0..partySize {
+            let pkmnAddress = partyAddr + GEN2_PARTY_PKMN_OFF + (creature * GEN2_PKMN_SIZE);
+            let nickAddress = partyAddr + GEN2_PARTY_NICK_OFF + (creature * GEN2_NAME_LENGTH);
+            let otnAddress = partyAddr + GEN2_PARTY_OTN_OFF + (creature * GEN2_NAME_LENGTH);
+
+            party.push(Self::getPokemonFromSave(save, &pkmnAddress, &nickAddress, &otnAddress)?);
+        }
+
+        return Ok(party);
+    }
+
+    /// Retrieves a single party Pokemon
+    ///
+    /// More Info [here](https://bulbapedia.bulbagarden.net/wiki/Pok%C3%A9mon_data_structure_(Generation_II))
+    fn getPokemonFromSave(save: &Vec<u8>, pkmnAddress: &usize, nickAddress: &usize, otnAddress: &usize) -> Result<Gen2Pokemon, String> {
+        let nickname = textDecode(&Self::getEncodedNameFromSave(save, nickAddress));
+        let otn = textDecode(&Self::getEncodedNameFromSave(save, otnAddress));
+        let ot = Self::getWordFromSave(save, &(pkmnAddress+GEN2_OT_OFF));
+        let exp = u32::from_be_bytes([0, save[pkmnAddress+GEN2_EXP_OFF], save[pkmnAddress+GEN2_EXP_OFF+1], save[pkmnAddress+GEN2_EXP_OFF+2]]);
+        let hp = Self::getWordFromSave(save, &(pkmnAddress+GEN2_HP_OFF)) as i16;
+        let moves = Self::getPokemonMovesFromSave(save, pkmnAddress)?;
+
+        let mut evs: [u16;5] = [0;5];
+        for stat in 0..5 {
+            evs[stat] = Self::getWordFromSave(save, &(pkmnAddress+GEN2_EV_OFF+(stat*2)));
+        }
+
+        let mut stats: [u16;6] = [0;6];
+        for stat in 0..6 {
+            stats[stat] = Self::getWordFromSave(save, &(pkmnAddress+GEN2_STAT_OFF+(stat*2)));
+        }
+
+        let caughtData = CaughtData::get([save[pkmnAddress+GEN2_CAUGHT_OFF], save[pkmnAddress+GEN2_CAUGHT_OFF+1]]);
+
+        return Gen2Pokemon::get(save[*pkmnAddress] as i16,
+                                save[pkmnAddress+GEN2_LEVEL_OFF] as i8,
+                                nickname,
+                                save[pkmnAddress+GEN2_ITEM_OFF],
+                                moves,
+                                ot,
+                                otn,
+                                exp,
+                                hp,
+                                evs,
+                                Self::getPokemonIVsFromSave(save, pkmnAddress),
+                                stats,
+                                save[pkmnAddress+GEN2_FRIENDSHIP_OFF],
+                                save[pkmnAddress+GEN2_POKERUS_OFF],
+                                caughtData
+                            );
+    }
+
+    /// Retrieves a big endian 2 byte value, which most Pokemon values are stored as
+    fn getWordFromSave(save: &Vec<u8>, currAddr: &usize) -> u16 {
+        return u16::from_be_bytes([save[*currAddr], save[currAddr+1]]);
+    }
+
+    /// Retrieves a Pokemons moves, which are stored the same way as in Gen 1
+    fn getPokemonMovesFromSave(save: &Vec<u8>, currAddr: &usize) -> Result<Vec<Move>, String> {
+        let mut moves: Vec<Move> = Vec::new();
+
+        for slot in 0..4 {
+            let moveIndex = save[currAddr+GEN2_MOVE_OFF+slot] as u16;
+            let ppValues = save[currAddr+GEN2_PP_OFF+slot];
+
+            if moveIndex == 0 {
+                moves.push(Move::empty());
+            } else {
+                moves.push(Move::get(moveIndex, (ppValues & 0x3F) as u16, ppValues >> 6)?);
+            }
+        }
+
+        return Ok(moves);
+    }
+
+    /// Retrieves a Pokemons Determinant Values, which are stored the same way as in Gen 1
+    fn getPokemonIVsFromSave(save: &Vec<u8>, currAddr: &usize) -> [u16;5] {
+        let atk = (save[currAddr+GEN2_IV_OFF] >> 4) as u16;
+        let def = (save[currAddr+GEN2_IV_OFF] & 0x0F) as u16;
+        let spd = (save[currAddr+GEN2_IV_OFF+1] >> 4) as u16;
+        let spc = (save[currAddr+GEN2_IV_OFF+1] & 0x0F) as u16;
+
+        // The HP DV is made from the last bit of the other four
+        let hp = (atk & 1) << 3 | (def & 1) << 2 | (spd & 1) << 1 | (spc & 1);
+
+        return [hp, atk, def, spd, spc];
+    }
+}
+
+
+#[cfg(test)]
+mod gen2SaveTests {
+    use super::*;
+    use super::super::creatureData::gen2Pokemon::{Gender, TimeOfDay};
+
+    #[test]
+    fn load_Crystal() {
+        let save = Gen2Save::load("./test/POKEMON CRYSTAL.sav").unwrap();
+
+        assert_eq!(save.getGameName(), "Crystal");
+        assert_eq!(save.getTrainerName(), "Kenta");
+        assert_eq!(save.getRivalName(), "Silver");
+        assert_eq!(save.getTrainerID(), &0xA1E9);
+        assert_eq!(save.getMoney(), &31089);
+        assert_eq!(save.getParty().len(), 5);
+        assert_eq!(save.getContainer().to_string(), "32 KiB SRAM with a 48 byte RTC footer");
+        assert_eq!(save.to_bytes(), fs::read("./test/POKEMON CRYSTAL.sav").unwrap());
+    }
+
+    #[test]
+    fn load_CrystalPokemon() {
+        let save = Gen2Save::load("./test/POKEMON CRYSTAL.sav").unwrap();
+        let croconaw = &save.getParty()[2];
+
+        assert_eq!(croconaw.getSpecies().getName(), "Croconaw");
+        assert_eq!(croconaw.getLevel(), &22);
+        assert_eq!(croconaw.getOTID(), save.getTrainerID());
+        assert_eq!(croconaw.getOTN(), "Kenta");
+        assert_eq!(croconaw.getHeldItem(), &0x7E);
+        assert_eq!(croconaw.getHP(), &68);
+        assert_eq!(croconaw.getStats().getHP(), &68);
+        assert_eq!(croconaw.getSPCDEF(), &41);
+        assert_eq!(croconaw.getMoves()[0].getName(), "Scratch");
+        assert_eq!(croconaw.getCaughtData().getLevel(), &5);
+        assert_eq!(croconaw.getCaughtData().getTime(), &TimeOfDay::Morning);
+        assert_eq!(croconaw.getGender(), Gender::Male);
+        assert_eq!(croconaw.isShiny(), false);
+    }
+
+    #[test]
+    fn load_CrystalTradedPokemon() {
+        let save = Gen2Save::load("./test/POKEMON CRYSTAL.sav").unwrap();
+        let espeon = &save.getParty()[0];
+
+        assert_eq!(espeon.getSpecies().getName(), "Espeon");
+        assert_ne!(espeon.getOTID(), save.getTrainerID());
+        // Moves from Gen 2 are read as well
+        assert_eq!(espeon.getMoves()[3].getName(), "Shadow Ball");
+    }
+
+    #[test]
+    fn load_Gen1Save() {
+        let result = Gen2Save::load("./test/POKEMON YELLOW.sav");
+
+        assert_eq!(result.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: File does not seem to be a Gen 2 Save File");
+    }
+
+    #[test]
+    fn load_MissingFile() {
+        let result = Gen2Save::load("./test/POKEMON GOLD.sav");
+
+        assert_eq!(result.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Save \"./test/POKEMON GOLD.sav\" does not exist");
+    }
+}
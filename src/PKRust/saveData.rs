@@ -0,0 +1,19 @@
+/// Module for organising all save data that isn't a Pokemon.
+/// 
+/// This includes data such as:
+/// - Items
+/// - Pokedex progress
+/// - Badges, play time and options
+/// - The Hall of Fame
+/// - The players position on the map
+/// - Event flags and missable objects
+/// - Which version of the game the save is from
+pub mod item;
+pub mod pokedex;
+pub mod badges;
+pub mod playTime;
+pub mod options;
+pub mod hallOfFame;
+pub mod position;
+pub mod eventFlags;
+pub mod gameVersion;
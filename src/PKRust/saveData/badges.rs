@@ -0,0 +1,145 @@
+use super::super::utils::formatError;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/// The eight Kanto Gym badges, in the order of their bits in the save
+pub enum Badge {
+    Boulder     = 0,
+    Cascade     = 1,
+    Thunder     = 2,
+    Rainbow     = 3,
+    Soul        = 4,
+    Marsh       = 5,
+    Volcano     = 6,
+    Earth       = 7,
+}
+impl Badge {
+    /// Returns the badge for an index from 0-7
+    pub fn get(index: usize) -> Result<Badge, String> {
+        let badge = match index {
+            0   => Badge::Boulder,
+            1   => Badge::Cascade,
+            2   => Badge::Thunder,
+            3   => Badge::Rainbow,
+            4   => Badge::Soul,
+            5   => Badge::Marsh,
+            6   => Badge::Volcano,
+            7   => Badge::Earth,
+            _   => return Err(formatError(format!("Badge \"{}\" does not exist, there are only 8 badges", index)))
+        };
+
+        return Ok(badge);
+    }
+
+    pub fn getName(&self) -> &'static str {
+        return match self {
+            Badge::Boulder  => "Boulder Badge",
+            Badge::Cascade  => "Cascade Badge",
+            Badge::Thunder  => "Thunder Badge",
+            Badge::Rainbow  => "Rainbow Badge",
+            Badge::Soul     => "Soul Badge",
+            Badge::Marsh    => "Marsh Badge",
+            Badge::Volcano  => "Volcano Badge",
+            Badge::Earth    => "Earth Badge",
+        };
+    }
+}
+
+#[derive(Debug)]
+/// The badges the player has obtained, stored as one bit per badge
+pub struct Badges {
+    flags: u8
+}
+impl Badges {
+    /// Constructor for Badges, when being read from a save file
+    pub fn get(flags: u8) -> Badges {
+        return Badges{flags};
+    }
+
+    /// Returns a string listing every obtained badge
+    pub fn to_string(&self) -> String {
+        let badgeNames: Vec<&str> = self.getObtained().iter().map(|badge| badge.getName()).collect();
+        return badgeNames.join(", ");
+    }
+
+    // ========   GETTERS   ========
+
+    /// Getter for the raw badge byte
+    pub fn getFlags(&self) -> &u8 {
+        return &self.flags;
+    }
+
+    /// Returns whether a badge has been obtained
+    pub fn has(&self, badge: Badge) -> bool {
+        return (self.flags >> badge as u8) & 1 == 1;
+    }
+
+    /// Returns a list of every obtained badge
+    pub fn getObtained(&self) -> Vec<Badge> {
+        let mut obtained: Vec<Badge> = Vec::new();
+
+        for index in 0..8 {
+            let badge = Badge::get(index).unwrap();
+            if self.has(badge) {
+                obtained.push(badge);
+            }
+        }
+
+        return obtained;
+    }
+
+    /// Returns how many badges have been obtained
+    pub fn getCount(&self) -> u32 {
+        return self.flags.count_ones();
+    }
+
+    // ========   SETTERS   ========
+
+    /// Setter for whether a badge has been obtained
+    pub fn set(&mut self, badge: Badge, obtained: bool) {
+        if obtained {
+            self.flags |= 1 << badge as u8;
+        } else {
+            self.flags &= !(1 << badge as u8);
+        }
+    }
+}
+
+#[cfg(test)]
+mod badgeTests {
+    use super::*;
+
+    #[test]
+    fn get_Correct() {
+        assert_eq!(Badge::get(7).unwrap(), Badge::Earth);
+    }
+
+    #[test]
+    fn get_Incorrect() {
+        let badgeResult = Badge::get(8);
+
+        assert!(badgeResult.is_err());
+        assert_eq!(badgeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Badge \"8\" does not exist, there are only 8 badges");
+    }
+
+    #[test]
+    fn set_Correct() {
+        let mut testBadges = Badges::get(0);
+
+        testBadges.set(Badge::Cascade, true);
+        testBadges.set(Badge::Earth, true);
+
+        assert_eq!(testBadges.getFlags(), &0b1000_0010);
+        assert_eq!(testBadges.getCount(), 2);
+        assert_eq!(testBadges.to_string(), "Cascade Badge, Earth Badge");
+    }
+
+    #[test]
+    fn set_Remove() {
+        let mut testBadges = Badges::get(0xFF);
+
+        testBadges.set(Badge::Boulder, false);
+
+        assert_eq!(testBadges.has(Badge::Boulder), false);
+        assert_eq!(testBadges.getCount(), 7);
+    }
+}
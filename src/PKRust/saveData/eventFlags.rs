@@ -0,0 +1,222 @@
+use std::fs;
+use super::super::utils::formatError;
+use super::gameVersion::GameVersion;
+
+/// There are 0xA00 event flags, stored in 0x140 bytes
+pub const EVENT_FLAG_BYTES: usize = 0x140;
+/// There are 0x100 missable objects, stored in 0x20 bytes
+pub const MISSABLE_BYTES: usize = 0x20;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FlagKind {
+    /// Story progress, such as gifts received and trainers beaten
+    Event,
+    /// Whether an object on a map (a person, an item ball or a legendary) is hidden
+    Missable,
+}
+
+#[derive(Debug)]
+/**
+ * The players story progress, stored as two bitfields
+ * events: Flags for gifts received, trainers beaten and other events
+ * missables: Flags for objects that have been hidden from their maps
+ *
+ * Flags can be looked up by the names in `data/flags.pkmn`, or directly by their index.
+ * Yellow adds objects to some maps, so later missables only have their Red/Blue index listed.
+ *
+ * More Info [here](https://github.com/pret/pokered/blob/master/constants/event_constants.asm)
+ */
+pub struct EventFlags {
+    events: [u8; EVENT_FLAG_BYTES],
+    missables: [u8; MISSABLE_BYTES]
+}
+impl EventFlags {
+    /// Constructor for EventFlags, when being read from a save file
+    pub fn get(events: [u8; EVENT_FLAG_BYTES], missables: [u8; MISSABLE_BYTES]) -> EventFlags {
+        return EventFlags{events, missables};
+    }
+
+    /// Returns a set of flags with nothing set
+    pub fn new() -> EventFlags {
+        return EventFlags{events: [0; EVENT_FLAG_BYTES], missables: [0; MISSABLE_BYTES]};
+    }
+
+    /// Looks up the kind and index of a named flag
+    pub fn findFlag(name: &str) -> Result<(FlagKind, usize), String> {
+        let flagFile = fs::read_to_string("./data/flags.pkmn").unwrap();
+
+        for line in flagFile.lines().skip(1) {
+            let parsedFlag: Vec<&str> = line.split(' ').collect();
+            if parsedFlag[2] != name {
+                continue;
+            }
+
+            let kind = match parsedFlag[0] {
+                "Event"     => FlagKind::Event,
+                _           => FlagKind::Missable
+            };
+            let index = usize::from_str_radix(parsedFlag[1].trim_start_matches("0x"), 16).unwrap();
+
+            return Ok((kind, index));
+        }
+
+        return Err(formatError(format!("Flag \"{}\" not found.", name)));
+    }
+
+    /// Checks that a named flag has the same index in the given version
+    pub fn checkFlagVersion(name: &str, version: &GameVersion) -> Result<bool, String> {
+        let flagFile = fs::read_to_string("./data/flags.pkmn").unwrap();
+
+        for line in flagFile.lines().skip(1) {
+            let parsedFlag: Vec<&str> = line.split(' ').collect();
+            if parsedFlag[2] != name {
+                continue;
+            }
+
+            if parsedFlag[3] == "RedBlue" && version.isYellow() {
+                return Err(formatError(format!("Flag \"{}\" is only listed for Red/Blue, but this save is from {}", name, version.getName())));
+            }
+
+            return Ok(true);
+        }
+
+        return Err(formatError(format!("Flag \"{}\" not found.", name)));
+    }
+
+    /// Returns the bitfield for a kind of flag
+    fn getField(&self, kind: FlagKind) -> &[u8] {
+        return match kind {
+            FlagKind::Event     => &self.events,
+            FlagKind::Missable  => &self.missables
+        };
+    }
+
+    // ========   GETTERS   ========
+
+    /// Getter for the raw event flag bitfield
+    pub fn getEventFlags(&self) -> &[u8; EVENT_FLAG_BYTES] {
+        return &self.events;
+    }
+
+    /// Getter for the raw missable object bitfield
+    pub fn getMissableFlags(&self) -> &[u8; MISSABLE_BYTES] {
+        return &self.missables;
+    }
+
+    /// Returns whether a flag is set, given its index
+    pub fn isSet(&self, kind: FlagKind, index: usize) -> Result<bool, String> {
+        let field = self.getField(kind);
+
+        if index >= field.len() * 8 {
+            return Err(formatError(format!("{:?} flag \"0x{:X}\" is over allowed maximum 0x{:X}", kind, index, field.len() * 8 - 1)));
+        }
+
+        return Ok((field[index / 8] >> (index % 8)) & 1 == 1);
+    }
+
+    /// Returns whether a flag is set, given its name
+    pub fn isSetByName(&self, name: &str) -> Result<bool, String> {
+        let (kind, index) = Self::findFlag(name)?;
+        return self.isSet(kind, index);
+    }
+
+    // ========   SETTERS   ========
+
+    /// Sets or clears a flag, given its index
+    pub fn set(&mut self, kind: FlagKind, index: usize, value: bool) -> Result<bool, String> {
+        let field: &mut [u8] = match kind {
+            FlagKind::Event     => &mut self.events,
+            FlagKind::Missable  => &mut self.missables
+        };
+
+        if index >= field.len() * 8 {
+            return Err(formatError(format!("{:?} flag \"0x{:X}\" is over allowed maximum 0x{:X}", kind, index, field.len() * 8 - 1)));
+        }
+
+        if value {
+            field[index / 8] |= 1 << (index % 8);
+        } else {
+            field[index / 8] &= !(1 << (index % 8));
+        }
+
+        return Ok(true);
+    }
+
+    /// Sets or clears a flag, given its name
+    pub fn setByName(&mut self, name: &str, value: bool) -> Result<bool, String> {
+        let (kind, index) = Self::findFlag(name)?;
+        return self.set(kind, index, value);
+    }
+}
+
+#[cfg(test)]
+mod eventFlagTests {
+    use super::*;
+
+    #[test]
+    fn findFlag_Correct() {
+        let (kind, index) = EventFlags::findFlag("BEAT_BROCK").unwrap();
+
+        assert_eq!(kind, FlagKind::Event);
+        assert_eq!(index, 0x077);
+    }
+
+    #[test]
+    fn findFlag_Missable() {
+        let (kind, index) = EventFlags::findFlag("LYING_OLD_MAN").unwrap();
+
+        assert_eq!(kind, FlagKind::Missable);
+        assert_eq!(index, 0x01);
+    }
+
+    #[test]
+    fn findFlag_Incorrect() {
+        let flagResult = EventFlags::findFlag("BEAT_GARY");
+
+        assert!(flagResult.is_err());
+        assert_eq!(flagResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Flag \"BEAT_GARY\" not found.");
+    }
+
+    #[test]
+    fn checkFlagVersion_RedBlueOnly() {
+        assert_eq!(EventFlags::checkFlagVersion("ZAPDOS", &GameVersion::Yellow), Ok(true));
+        assert_eq!(EventFlags::checkFlagVersion("ARTICUNO", &GameVersion::JapaneseRedGreenBlue), Ok(true));
+
+        let flagResult = EventFlags::checkFlagVersion("ARTICUNO", &GameVersion::Yellow);
+
+        assert!(flagResult.is_err());
+        assert_eq!(flagResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Flag \"ARTICUNO\" is only listed for Red/Blue, but this save is from Yellow");
+    }
+
+    #[test]
+    fn setByName_Correct() {
+        let mut testFlags = EventFlags::new();
+
+        let flagResult = testFlags.setByName("BEAT_BROCK", true);
+
+        assert!(flagResult.is_ok());
+        assert_eq!(testFlags.isSetByName("BEAT_BROCK"), Ok(true));
+        // 0x077 is bit 7 of byte 0x0E
+        assert_eq!(testFlags.getEventFlags()[0x0E], 0b1000_0000);
+    }
+
+    #[test]
+    fn set_Clear() {
+        let mut testFlags = EventFlags::get([0xFF; EVENT_FLAG_BYTES], [0xFF; MISSABLE_BYTES]);
+
+        testFlags.set(FlagKind::Missable, 0x09, false).unwrap();
+
+        assert_eq!(testFlags.isSet(FlagKind::Missable, 0x09), Ok(false));
+        assert_eq!(testFlags.getMissableFlags()[1], 0b1111_1101);
+    }
+
+    #[test]
+    fn set_IncorrectIndex() {
+        let mut testFlags = EventFlags::new();
+
+        let flagResult = testFlags.set(FlagKind::Missable, 0x100, true);
+
+        assert!(flagResult.is_err());
+        assert_eq!(flagResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Missable flag \"0x100\" is over allowed maximum 0xFF");
+    }
+}
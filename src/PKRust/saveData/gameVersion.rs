@@ -0,0 +1,119 @@
+use super::super::addresses::{SaveLayout, GameRegion};
+
+/// Pikachu's species index, as it is the starter in Yellow
+pub const PIKACHU_INDEX: i16 = 0x54;
+/// Bulbasaur, Charmander and Squirtle
+const RED_BLUE_STARTERS: [i16; 3] = [0x99, 0xB0, 0xB1];
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/**
+ * The version of the game a save is from.
+ *
+ * Saves don't store their version, so it is guessed from the layout and the starter.
+ * Red, Blue and Green all share the same save data, so they can't be told apart.
+ */
+pub enum GameVersion {
+    RedBlue,
+    Yellow,
+    /// Japanese Red, Green and Blue
+    JapaneseRedGreenBlue,
+    JapaneseYellow,
+}
+impl GameVersion {
+    /// Guesses the version of a save, from its layout and story data
+    ///
+    /// Yellow is found by its starter always being Pikachu. Before the starter is received
+    /// it is told apart by Pikachu's friendship, which Red and Blue leave as 0.
+    pub fn detect(save: &Vec<u8>, layout: &SaveLayout) -> GameVersion {
+        let starter = save[layout.starterAddr] as i16;
+        let friendship = save[layout.pikachuFriendshipAddr];
+
+        let isYellow = starter == PIKACHU_INDEX || (!RED_BLUE_STARTERS.contains(&starter) && friendship != 0);
+
+        return match (layout.region, isYellow) {
+            (GameRegion::International, false)  => GameVersion::RedBlue,
+            (GameRegion::International, true)   => GameVersion::Yellow,
+            (GameRegion::Japanese, false)       => GameVersion::JapaneseRedGreenBlue,
+            (GameRegion::Japanese, true)        => GameVersion::JapaneseYellow,
+        };
+    }
+
+    /// Returns the name of the version
+    pub fn getName(&self) -> String {
+        let name = match self {
+            GameVersion::RedBlue                => "Red/Blue",
+            GameVersion::Yellow                 => "Yellow",
+            GameVersion::JapaneseRedGreenBlue   => "Red/Green/Blue (Japanese)",
+            GameVersion::JapaneseYellow         => "Yellow (Japanese)",
+        };
+
+        return String::from(name);
+    }
+
+    /// Returns the region of the version, which decides the layout of its saves
+    pub fn getRegion(&self) -> GameRegion {
+        return match self {
+            GameVersion::RedBlue | GameVersion::Yellow                      => GameRegion::International,
+            GameVersion::JapaneseRedGreenBlue | GameVersion::JapaneseYellow => GameRegion::Japanese,
+        };
+    }
+
+    /// Returns whether the version is Yellow, in any region
+    pub fn isYellow(&self) -> bool {
+        return matches!(self, GameVersion::Yellow | GameVersion::JapaneseYellow);
+    }
+}
+
+#[cfg(test)]
+mod gameVersionTests {
+    use super::*;
+    use super::super::super::addresses::{INTERNATIONAL, JAPANESE, SAVE_SIZE};
+
+    #[test]
+    fn detect_PikachuStarter() {
+        let mut save = vec![0; SAVE_SIZE];
+        save[INTERNATIONAL.starterAddr] = 0x54;
+
+        assert_eq!(GameVersion::detect(&save, &INTERNATIONAL), GameVersion::Yellow);
+    }
+
+    #[test]
+    fn detect_RedBlueStarter() {
+        let mut save = vec![0; SAVE_SIZE];
+        save[INTERNATIONAL.starterAddr] = 0xB0;
+
+        assert_eq!(GameVersion::detect(&save, &INTERNATIONAL), GameVersion::RedBlue);
+    }
+
+    #[test]
+    fn detect_FriendshipBeforeStarter() {
+        let mut save = vec![0; SAVE_SIZE];
+        save[JAPANESE.pikachuFriendshipAddr] = 90;
+
+        assert_eq!(GameVersion::detect(&save, &JAPANESE), GameVersion::JapaneseYellow);
+    }
+
+    #[test]
+    fn detect_JapaneseYellowFriendship() {
+        // Japanese Yellow keeps Pikachu's friendship 0xA before the International address
+        let mut save = vec![0; SAVE_SIZE];
+        save[0x2712] = 90;
+
+        assert_eq!(JAPANESE.pikachuFriendshipAddr, 0x2712);
+        assert_eq!(GameVersion::detect(&save, &JAPANESE), GameVersion::JapaneseYellow);
+    }
+
+    #[test]
+    fn detect_NewGame() {
+        let save = vec![0; SAVE_SIZE];
+
+        assert_eq!(GameVersion::detect(&save, &JAPANESE), GameVersion::JapaneseRedGreenBlue);
+    }
+
+    #[test]
+    fn getRegion_Correct() {
+        assert_eq!(GameVersion::Yellow.getRegion(), GameRegion::International);
+        assert_eq!(GameVersion::JapaneseYellow.getRegion(), GameRegion::Japanese);
+        assert!(GameVersion::JapaneseYellow.isYellow());
+    }
+}
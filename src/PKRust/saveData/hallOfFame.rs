@@ -0,0 +1,255 @@
+use super::super::creatureData::pokemonSpecies::Species;
+use super::super::utils::{formatError, textEncode, kanaEncode};
+
+/// The Hall of Fame only keeps the 50 most recent teams
+pub const HOF_MAX_RECORDS: usize = 50;
+
+#[derive(Debug)]
+/// A single Pokemon in a Hall of Fame team
+pub struct HallOfFameEntry {
+    species: Species,
+    level: u8,
+    nickname: String,
+    /// The nickname as it was read from the save, until it is set
+    encodedNickname: Option<[i16;11]>
+}
+impl HallOfFameEntry {
+    /// Constructor for an entry, when being read from a save file
+    pub fn get(index: i16, level: u8, nickname: String) -> Result<HallOfFameEntry, String> {
+        let species = Species::parse(index)?;
+        return Ok(HallOfFameEntry{species, level, nickname, encodedNickname: None});
+    }
+
+    /// Constructor for an entry whose species isn't in the species list, such as a glitch Pokemon
+    pub fn unknown(index: i16, level: u8, nickname: String) -> HallOfFameEntry {
+        return HallOfFameEntry{species: Species::unknown(index), level, nickname, encodedNickname: None};
+    }
+
+    /// Keeps the nickname as it was read from the save, so that it is written back unchanged
+    pub fn keepEncodedNickname(&mut self, nickname: [i16;11]) {
+        self.encodedNickname = Some(nickname);
+    }
+
+    /// Returns a string with the entry's species, nickname and level
+    pub fn to_string(&self) -> String {
+        return format!("{:12} {:12} LVL:{}", self.species.getName(), self.nickname, self.level);
+    }
+
+    pub fn getSpecies(&self) -> &Species {
+        return &self.species;
+    }
+
+    pub fn getLevel(&self) -> &u8 {
+        return &self.level;
+    }
+
+    pub fn getNickname(&self) -> &String {
+        return &self.nickname;
+    }
+
+    /// Getter for the nickname as it was read from the save, if it hasn't been set since
+    pub fn getEncodedNickname(&self) -> &Option<[i16;11]> {
+        return &self.encodedNickname;
+    }
+}
+
+#[derive(Debug)]
+/**
+ * The teams that have entered the Hall of Fame
+ * records: Up to 50 teams of up to 6 Pokemon, oldest first
+ * count: How many times the player has entered the Hall of Fame,
+ *        which keeps counting past 50 (up to 255)
+ *
+ * More Info [here](https://bulbapedia.bulbagarden.net/wiki/Save_data_structure_(Generation_I)#Hall_of_Fame)
+ */
+pub struct HallOfFame {
+    records: Vec<Vec<HallOfFameEntry>>,
+    count: u8
+}
+impl HallOfFame {
+    /// Constructor for the Hall of Fame, when being read from a save file
+    pub fn get(records: Vec<Vec<HallOfFameEntry>>, count: u8) -> HallOfFame {
+        return HallOfFame{records, count};
+    }
+
+    /// Returns an empty Hall of Fame
+    pub fn new() -> HallOfFame {
+        return HallOfFame{records: Vec::new(), count: 0};
+    }
+
+    /// Returns a string with every team in the Hall of Fame
+    pub fn to_string(&self) -> String {
+        let mut hallOfFame = String::new();
+
+        for (record, team) in self.records.iter().enumerate() {
+            hallOfFame.push_str(&format!("Record {}\n", record + 1));
+            for entry in team {
+                hallOfFame.push_str(&format!("\t{}\n", entry.to_string()));
+            }
+        }
+
+        return hallOfFame;
+    }
+
+    /// Checks that there is an entry at the record and slot
+    fn checkEntryExists(&self, record: usize, slot: usize) -> Result<bool, String> {
+        if record >= self.records.len() {
+            return Err(formatError(format!("There is no Hall of Fame record {}", record)));
+        } else if slot >= self.records[record].len() {
+            return Err(formatError(format!("There is no Pokemon in slot {} of Hall of Fame record {}", slot, record)));
+        }
+
+        return Ok(true);
+    }
+
+    // ========   GETTERS   ========
+
+    pub fn getRecords(&self) -> &Vec<Vec<HallOfFameEntry>> {
+        return &self.records;
+    }
+
+    /// Returns how many times the player has entered the Hall of Fame
+    pub fn getCount(&self) -> &u8 {
+        return &self.count;
+    }
+
+    // ========   SETTERS   ========
+
+    /// Removes every team from the Hall of Fame, and resets the count
+    pub fn clear(&mut self) {
+        self.records.clear();
+        self.count = 0;
+    }
+
+    /// Setter for the species of an entry
+    pub fn setSpecies(&mut self, record: usize, slot: usize, index: i16) -> Result<bool, String> {
+        self.checkEntryExists(record, slot)?;
+
+        let species = Species::parse(index).map_err(formatError)?;
+        self.records[record][slot].species = species;
+
+        return Ok(true);
+    }
+
+    /// Setter for the level of an entry
+    pub fn setLevel(&mut self, record: usize, slot: usize, newLevel: u8) -> Result<bool, String> {
+        self.checkEntryExists(record, slot)?;
+
+        if newLevel > 100 {
+            return Err(formatError(format!("Value of new level \"{}\" is over allowed maximum 100", newLevel)));
+        } else if newLevel < 1 {
+            return Err(formatError(format!("Value of new level \"{}\" is under allowed minimum 1", newLevel)));
+        }
+
+        self.records[record][slot].level = newLevel;
+
+        return Ok(true);
+    }
+
+    /// Setter for the nickname of an entry
+    pub fn setNickname(&mut self, record: usize, slot: usize, newNickname: String) -> Result<bool, String> {
+        self.checkEntryExists(record, slot)?;
+
+        if newNickname.chars().count() > 10 {
+            return Err(formatError(format!("Length of nickname \"{}\" is over 10 characters.", newNickname)));
+        }
+        // Teams from Japanese saves have kana names
+        textEncode(&newNickname).or_else(|error| kanaEncode(&newNickname).map_err(|_| error))?;
+
+        self.records[record][slot].nickname = newNickname;
+        self.records[record][slot].encodedNickname = None;
+
+        return Ok(true);
+    }
+}
+
+#[cfg(test)]
+mod hallOfFameTests {
+    use super::*;
+
+    fn testHallOfFame() -> HallOfFame {
+        let team = vec![
+            HallOfFameEntry::get(0x99, 60, String::from("BULBASAUR")).unwrap(),
+            HallOfFameEntry::get(0x54, 62, String::from("PIKACHU")).unwrap(),
+        ];
+
+        return HallOfFame::get(vec![team], 1);
+    }
+
+    #[test]
+    fn get_IncorrectSpecies() {
+        let entryResult = HallOfFameEntry::get(0x00, 50, String::from("MISSINGNO"));
+
+        assert!(entryResult.is_err());
+    }
+
+    #[test]
+    fn clear_Correct() {
+        let mut testHoF = testHallOfFame();
+
+        testHoF.clear();
+
+        assert!(testHoF.getRecords().is_empty());
+        assert_eq!(testHoF.getCount(), &0);
+    }
+
+    #[test]
+    fn setSpecies_Correct() {
+        let mut testHoF = testHallOfFame();
+
+        let speciesResult = testHoF.setSpecies(0, 1, 0x15);
+
+        assert!(speciesResult.is_ok());
+        assert_eq!(testHoF.getRecords()[0][1].getSpecies().getName(), "Mew");
+    }
+
+    #[test]
+    fn setLevel_Correct() {
+        let mut testHoF = testHallOfFame();
+
+        let levelResult = testHoF.setLevel(0, 0, 100);
+
+        assert!(levelResult.is_ok());
+        assert_eq!(testHoF.getRecords()[0][0].getLevel(), &100);
+    }
+
+    #[test]
+    fn setLevel_IncorrectOver() {
+        let mut testHoF = testHallOfFame();
+
+        let levelResult = testHoF.setLevel(0, 0, 101);
+
+        assert!(levelResult.is_err());
+        assert_eq!(levelResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Value of new level \"101\" is over allowed maximum 100");
+    }
+
+    #[test]
+    fn setLevel_IncorrectRecord() {
+        let mut testHoF = testHallOfFame();
+
+        let levelResult = testHoF.setLevel(1, 0, 50);
+
+        assert!(levelResult.is_err());
+        assert_eq!(levelResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: There is no Hall of Fame record 1");
+    }
+
+    #[test]
+    fn setNickname_IncorrectSlot() {
+        let mut testHoF = testHallOfFame();
+
+        let nicknameResult = testHoF.setNickname(0, 2, String::from("SPARKY"));
+
+        assert!(nicknameResult.is_err());
+        assert_eq!(nicknameResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: There is no Pokemon in slot 2 of Hall of Fame record 0");
+    }
+
+    #[test]
+    fn setNickname_IncorrectLength() {
+        let mut testHoF = testHallOfFame();
+
+        let nicknameResult = testHoF.setNickname(0, 0, String::from("Jimbosaurus Rex"));
+
+        assert!(nicknameResult.is_err());
+        assert_eq!(nicknameResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Length of nickname \"Jimbosaurus Rex\" is over 10 characters.");
+    }
+}
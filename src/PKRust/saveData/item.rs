@@ -0,0 +1,331 @@
+use std::fs;
+use super::super::utils::formatError;
+
+#[derive(Debug)]
+/**
+ * A stack of items, as stored in the bag or the PC
+ * index: The index of the item in Gen 1 (0x01 being the Master Ball)
+ * name: The string name of the item
+ * quantity: How many of the item are in the stack (1-99)
+ */
+pub struct Item {
+    index: u8,
+    name: String,
+    quantity: u8
+}
+impl Item {
+    /// Constructor for an Item, given an input item index
+    pub fn get(index: u8, quantity: u8) -> Result<Item, String> {
+        let itemFile = fs::read_to_string("./data/items.pkmn").unwrap();
+        let hexIndex = format!("0x{:02X}", index);
+        let mut itemLine: &str = "No Item found";
+
+        for line in itemFile.lines() {
+            if line.split(' ').next() == Some(hexIndex.as_str()) {
+                itemLine = line;
+                break;
+            }
+        }
+
+        let parsedItem: Vec<&str> = itemLine.split(' ').collect();
+        if parsedItem[0] != hexIndex {
+            return Err(format!("Item with ID {hexIndex} not found."));
+        }
+        let name = parsedItem[1].replace('+', " ");
+
+        return Ok(Item{index, name, quantity});
+    }
+
+    /// Constructor for an item that isn't in the item list, such as a glitch item
+    pub fn unknown(index: u8, quantity: u8) -> Item {
+        return Item{index, name: String::from("Unknown"), quantity};
+    }
+
+    /// Returns the item and its quantity for printing
+    pub fn to_string(&self) -> String {
+        return format!("{} x{}", self.name, self.quantity);
+    }
+
+    pub fn getIndex(&self) -> &u8 {
+        return &self.index;
+    }
+
+    pub fn getName(&self) -> &String {
+        return &self.name;
+    }
+
+    pub fn getQuantity(&self) -> &u8 {
+        return &self.quantity;
+    }
+
+    /// Setter for the quantity of the item
+    ///
+    /// A stack can only hold 1-99 of an item.
+    pub fn setQuantity(&mut self, newQuantity: u8) -> Result<bool, String> {
+
+        if newQuantity > 99 {
+            return Err(formatError(format!("Quantity \"{}\" is over allowed maximum 99", newQuantity)));
+        } else if newQuantity < 1 {
+            return Err(formatError(format!("Quantity \"{}\" is under allowed minimum 1", newQuantity)));
+        }
+
+        self.quantity = newQuantity;
+
+        return Ok(true);
+    }
+}
+
+#[derive(Debug)]
+/// A list of items, such as the bag (20 items) or the PC (50 items)
+pub struct Inventory {
+    items: Vec<Item>,
+    capacity: usize
+}
+impl Inventory {
+    /// Constructor for an Inventory from already loaded items
+    pub fn get(items: Vec<Item>, capacity: usize) -> Inventory {
+        return Inventory{items, capacity};
+    }
+
+    /// Returns an empty Inventory
+    pub fn new(capacity: usize) -> Inventory {
+        return Inventory{items: Vec::new(), capacity};
+    }
+
+    /// Returns a string with every item on its own line
+    pub fn to_string(&self) -> String {
+        let mut itemList = String::new();
+
+        for item in &self.items {
+            itemList.push_str(&format!("\t{}\n", item.to_string()));
+        }
+
+        return itemList;
+    }
+
+    // ========   GETTERS   ========
+
+    pub fn getItems(&self) -> &Vec<Item> {
+        return &self.items;
+    }
+
+    pub fn getCapacity(&self) -> &usize {
+        return &self.capacity;
+    }
+
+    // ========   SETTERS   ========
+
+    /// Adds an amount of an item.
+    ///
+    /// If the item is already in the inventory, it is added to that stack.
+    /// Otherwise a new stack is made, as long as the inventory isn't full.
+    pub fn addItem(&mut self, index: u8, quantity: u8) -> Result<bool, String> {
+
+        // First we check if there is already a stack to add to
+        if let Some(item) = self.items.iter_mut().find(|item| item.index == index) {
+            let newQuantity = item.quantity as u16 + quantity as u16;
+
+            if newQuantity > 99 {
+                return Err(formatError(format!("Adding {} to {} would go over the maximum of 99", quantity, item.to_string())));
+            }
+
+            item.quantity = newQuantity as u8;
+            return Ok(true);
+        }
+
+        // Then we check that there is space for a new stack
+        if self.items.len() >= self.capacity {
+            return Err(formatError(format!("Inventory is full, it can only hold {} items", self.capacity)));
+        }
+
+        let mut newItem = Item::get(index, 1).map_err(formatError)?;
+        newItem.setQuantity(quantity)?;
+
+        self.items.push(newItem);
+
+        return Ok(true);
+    }
+
+    /// Removes an amount of an item.
+    ///
+    /// If the whole stack is removed, the item is taken out of the inventory.
+    pub fn removeItem(&mut self, index: u8, quantity: u8) -> Result<bool, String> {
+
+        let position = match self.items.iter().position(|item| item.index == index) {
+            Some(position) => position,
+            None           => return Err(formatError(format!("There is no item with ID 0x{:02X} to remove", index)))
+        };
+
+        let currQuantity = self.items[position].quantity;
+
+        if quantity > currQuantity {
+            return Err(formatError(format!("Cannot remove {} from {}", quantity, self.items[position].to_string())));
+        } else if quantity == currQuantity {
+            self.items.remove(position);
+        } else {
+            self.items[position].quantity = currQuantity - quantity;
+        }
+
+        return Ok(true);
+    }
+
+    /// Sets the quantity of the item in an inventory slot
+    pub fn setQuantity(&mut self, slot: usize, newQuantity: u8) -> Result<bool, String> {
+
+        if slot >= self.items.len() {
+            return Err(formatError(format!("There is no item in slot {}", slot)));
+        }
+
+        return self.items[slot].setQuantity(newQuantity);
+    }
+}
+
+#[cfg(test)]
+mod itemTests {
+    use super::*;
+
+    #[test]
+    fn get_CorrectItem() {
+        let testItem = Item::get(0x01, 5).unwrap();
+
+        assert_eq!(testItem.getName(), "Master Ball");
+        assert_eq!(testItem.getQuantity(), &5);
+    }
+
+    #[test]
+    fn get_CorrectTM() {
+        let testItem = Item::get(0xC9, 1).unwrap();
+
+        assert_eq!(testItem.getName(), "TM01");
+    }
+
+    #[test]
+    fn get_IncorrectItem() {
+        let testItem = Item::get(0x00, 1);
+
+        assert!(testItem.is_err());
+        assert_eq!(testItem.unwrap_err(), "Item with ID 0x00 not found.");
+    }
+
+    #[test]
+    fn setQuantity_Correct() {
+        let mut testItem = Item::get(0x14, 1).unwrap();
+
+        let quantityResult = testItem.setQuantity(99);
+
+        assert!(quantityResult.is_ok());
+        assert_eq!(testItem.getQuantity(), &99);
+    }
+
+    #[test]
+    fn setQuantity_IncorrectOver() {
+        let mut testItem = Item::get(0x14, 1).unwrap();
+
+        let quantityResult = testItem.setQuantity(100);
+
+        assert!(quantityResult.is_err());
+        assert_eq!(quantityResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Quantity \"100\" is over allowed maximum 99");
+    }
+
+    #[test]
+    fn setQuantity_IncorrectUnder() {
+        let mut testItem = Item::get(0x14, 1).unwrap();
+
+        let quantityResult = testItem.setQuantity(0);
+
+        assert!(quantityResult.is_err());
+        assert_eq!(quantityResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Quantity \"0\" is under allowed minimum 1");
+    }
+}
+
+#[cfg(test)]
+mod inventoryTests {
+    use super::*;
+
+    #[test]
+    fn addItem_NewStack() {
+        let mut testInventory = Inventory::new(20);
+
+        let addResult = testInventory.addItem(0x14, 5);
+
+        assert!(addResult.is_ok());
+        assert_eq!(testInventory.getItems()[0].getName(), "Potion");
+        assert_eq!(testInventory.getItems()[0].getQuantity(), &5);
+    }
+
+    #[test]
+    fn addItem_ExistingStack() {
+        let mut testInventory = Inventory::new(20);
+
+        testInventory.addItem(0x14, 5).unwrap();
+        testInventory.addItem(0x14, 10).unwrap();
+
+        assert_eq!(testInventory.getItems().len(), 1);
+        assert_eq!(testInventory.getItems()[0].getQuantity(), &15);
+    }
+
+    #[test]
+    fn addItem_IncorrectOverStack() {
+        let mut testInventory = Inventory::new(20);
+
+        testInventory.addItem(0x14, 90).unwrap();
+        let addResult = testInventory.addItem(0x14, 10);
+
+        assert!(addResult.is_err());
+        assert_eq!(addResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Adding 10 to Potion x90 would go over the maximum of 99");
+        assert_eq!(testInventory.getItems()[0].getQuantity(), &90);
+    }
+
+    #[test]
+    fn addItem_IncorrectFull() {
+        let mut testInventory = Inventory::new(2);
+
+        testInventory.addItem(0x01, 1).unwrap();
+        testInventory.addItem(0x02, 1).unwrap();
+        let addResult = testInventory.addItem(0x03, 1);
+
+        assert!(addResult.is_err());
+        assert_eq!(addResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Inventory is full, it can only hold 2 items");
+    }
+
+    #[test]
+    fn removeItem_Partial() {
+        let mut testInventory = Inventory::new(20);
+
+        testInventory.addItem(0x14, 5).unwrap();
+        let removeResult = testInventory.removeItem(0x14, 2);
+
+        assert!(removeResult.is_ok());
+        assert_eq!(testInventory.getItems()[0].getQuantity(), &3);
+    }
+
+    #[test]
+    fn removeItem_WholeStack() {
+        let mut testInventory = Inventory::new(20);
+
+        testInventory.addItem(0x14, 5).unwrap();
+        testInventory.removeItem(0x14, 5).unwrap();
+
+        assert!(testInventory.getItems().is_empty());
+    }
+
+    #[test]
+    fn removeItem_IncorrectMissing() {
+        let mut testInventory = Inventory::new(20);
+
+        let removeResult = testInventory.removeItem(0x14, 1);
+
+        assert!(removeResult.is_err());
+        assert_eq!(removeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: There is no item with ID 0x14 to remove");
+    }
+
+    #[test]
+    fn setQuantity_IncorrectSlot() {
+        let mut testInventory = Inventory::new(20);
+
+        let quantityResult = testInventory.setQuantity(0, 5);
+
+        assert!(quantityResult.is_err());
+        assert_eq!(quantityResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: There is no item in slot 0");
+    }
+}
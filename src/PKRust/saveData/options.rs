@@ -0,0 +1,155 @@
+use super::super::utils::formatError;
+
+#[derive(Debug, PartialEq)]
+pub enum BattleStyle {
+    /// The player is asked whether to switch when the opponent switches
+    Shift,
+    /// The player is not given the chance to switch
+    Set,
+}
+
+#[derive(Debug)]
+/**
+ * The options set in the options menu, all stored in one byte:
+ * bits 0-3: The text speed, as a frame delay (1 = fast, 3 = medium, 5 = slow)
+ * bit 6: The battle style (0 = shift, 1 = set)
+ * bit 7: Battle animations (0 = on, 1 = off)
+ *
+ * Bits 4 and 5 are used for the sound setting in Yellow, so they are left untouched.
+ */
+pub struct Options {
+    flags: u8
+}
+impl Options {
+    /// Constructor for Options, when being read from a save file
+    pub fn get(flags: u8) -> Options {
+        return Options{flags};
+    }
+
+    /// Returns a string to display all options
+    pub fn to_string(&self) -> String {
+        let textSpeed = match self.getTextSpeed() {
+            1 => "Fast",
+            3 => "Medium",
+            5 => "Slow",
+            _ => "Unknown"
+        };
+        let animation = if self.getBattleAnimation() { "On" } else { "Off" };
+
+        return format!("Text Speed: {}\nBattle Animation: {}\nBattle Style: {:?}", textSpeed, animation, self.getBattleStyle());
+    }
+
+    // ========   GETTERS   ========
+
+    /// Getter for the raw options byte
+    pub fn getFlags(&self) -> &u8 {
+        return &self.flags;
+    }
+
+    /// Returns the text speed as a frame delay
+    pub fn getTextSpeed(&self) -> u8 {
+        return self.flags & 0x0F;
+    }
+
+    /// Returns whether battle animations are turned on
+    pub fn getBattleAnimation(&self) -> bool {
+        return self.flags & 0x80 == 0;
+    }
+
+    pub fn getBattleStyle(&self) -> BattleStyle {
+        if self.flags & 0x40 == 0 {
+            return BattleStyle::Shift;
+        } else {
+            return BattleStyle::Set;
+        }
+    }
+
+    // ========   SETTERS   ========
+
+    /// Setter for the text speed
+    ///
+    /// The options menu only allows 1 (fast), 3 (medium) and 5 (slow).
+    pub fn setTextSpeed(&mut self, speed: u8) -> Result<bool, String> {
+
+        if speed != 1 && speed != 3 && speed != 5 {
+            return Err(formatError(format!("Text speed \"{}\" is not one of 1 (fast), 3 (medium) or 5 (slow)", speed)));
+        }
+
+        self.flags = (self.flags & 0xF0) | speed;
+
+        return Ok(true);
+    }
+
+    /// Setter for whether battle animations are turned on
+    pub fn setBattleAnimation(&mut self, on: bool) {
+        if on {
+            self.flags &= !0x80;
+        } else {
+            self.flags |= 0x80;
+        }
+    }
+
+    pub fn setBattleStyle(&mut self, style: BattleStyle) {
+        match style {
+            BattleStyle::Shift  => self.flags &= !0x40,
+            BattleStyle::Set    => self.flags |= 0x40,
+        }
+    }
+}
+
+#[cfg(test)]
+mod optionsTests {
+    use super::*;
+
+    #[test]
+    fn get_Correct() {
+        // Fast text, set style, animations on
+        let testOptions = Options::get(0b0100_0001);
+
+        assert_eq!(testOptions.getTextSpeed(), 1);
+        assert_eq!(testOptions.getBattleStyle(), BattleStyle::Set);
+        assert_eq!(testOptions.getBattleAnimation(), true);
+    }
+
+    #[test]
+    fn setTextSpeed_Correct() {
+        let mut testOptions = Options::get(0b0011_0001);
+
+        let speedResult = testOptions.setTextSpeed(5);
+
+        assert!(speedResult.is_ok());
+        // The Yellow sound bits should be left as they were
+        assert_eq!(testOptions.getFlags(), &0b0011_0101);
+    }
+
+    #[test]
+    fn setTextSpeed_Incorrect() {
+        let mut testOptions = Options::get(0b0000_0011);
+
+        let speedResult = testOptions.setTextSpeed(4);
+
+        assert!(speedResult.is_err());
+        assert_eq!(speedResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Text speed \"4\" is not one of 1 (fast), 3 (medium) or 5 (slow)");
+        assert_eq!(testOptions.getTextSpeed(), 3);
+    }
+
+    #[test]
+    fn setBattleAnimation_Correct() {
+        let mut testOptions = Options::get(0b0000_0011);
+
+        testOptions.setBattleAnimation(false);
+
+        assert_eq!(testOptions.getBattleAnimation(), false);
+        assert_eq!(testOptions.getFlags(), &0b1000_0011);
+    }
+
+    #[test]
+    fn setBattleStyle_Correct() {
+        let mut testOptions = Options::get(0b0100_0011);
+
+        testOptions.setBattleStyle(BattleStyle::Shift);
+
+        assert_eq!(testOptions.getBattleStyle(), BattleStyle::Shift);
+        assert_eq!(testOptions.getFlags(), &0b0000_0011);
+    }
+}
@@ -0,0 +1,159 @@
+use super::super::utils::formatError;
+
+#[derive(Debug)]
+/**
+ * The time the player has spent in game
+ * hours: 0-255
+ * maxed: Set once the clock reaches 255:59, at which point it stops counting
+ * minutes, seconds: 0-59
+ * frames: 0-59, as the Game Boy runs at roughly 60 frames per second
+ */
+pub struct PlayTime {
+    hours: u8,
+    maxed: u8,
+    minutes: u8,
+    seconds: u8,
+    frames: u8
+}
+impl PlayTime {
+    /// Constructor for PlayTime, when being read from a save file
+    pub fn get(hours: u8, maxed: u8, minutes: u8, seconds: u8, frames: u8) -> PlayTime {
+        return PlayTime{hours, maxed, minutes, seconds, frames};
+    }
+
+    /// Returns a blank PlayTime
+    pub fn new() -> PlayTime {
+        return PlayTime{hours: 0, maxed: 0, minutes: 0, seconds: 0, frames: 0};
+    }
+
+    /// Returns the play time as it is shown on the trainer card
+    pub fn to_string(&self) -> String {
+        return format!("{}:{:02}:{:02}", self.hours, self.minutes, self.seconds);
+    }
+
+    // ========   GETTERS   ========
+
+    pub fn getHours(&self) -> &u8 {
+        return &self.hours;
+    }
+
+    pub fn getMinutes(&self) -> &u8 {
+        return &self.minutes;
+    }
+
+    pub fn getSeconds(&self) -> &u8 {
+        return &self.seconds;
+    }
+
+    pub fn getFrames(&self) -> &u8 {
+        return &self.frames;
+    }
+
+    /// Getter for the raw "maxed" byte
+    pub fn getMaxedFlag(&self) -> &u8 {
+        return &self.maxed;
+    }
+
+    /// Returns whether the clock has stopped at its maximum
+    pub fn isMaxed(&self) -> bool {
+        return self.maxed != 0;
+    }
+
+    // ========   SETTERS   ========
+
+    /// Setter for the play time
+    ///
+    /// No validation is done on the hours, as the typing (`u8`) matches the games limit of 255.
+    pub fn setTime(&mut self, hours: u8, minutes: u8, seconds: u8, frames: u8) -> Result<bool, String> {
+
+        // First we check each value is within its range
+        if minutes > 59 {
+            return Err(formatError(format!("Minutes \"{}\" is over allowed maximum 59", minutes)));
+        } else if seconds > 59 {
+            return Err(formatError(format!("Seconds \"{}\" is over allowed maximum 59", seconds)));
+        } else if frames > 59 {
+            return Err(formatError(format!("Frames \"{}\" is over allowed maximum 59", frames)));
+        }
+
+        // Now that the checks are complete, we set the time
+        self.hours = hours;
+        self.minutes = minutes;
+        self.seconds = seconds;
+        self.frames = frames;
+
+        return Ok(true);
+    }
+
+    /// Setter for the "maxed" flag
+    ///
+    /// The game sets this to 0xFF once the clock reaches 255:59
+    pub fn setMaxed(&mut self, maxed: bool) {
+        self.maxed = if maxed { 0xFF } else { 0x00 };
+    }
+}
+
+#[cfg(test)]
+mod playTimeTests {
+    use super::*;
+
+    #[test]
+    fn to_string_Correct() {
+        let testTime = PlayTime::get(167, 0, 55, 4, 8);
+
+        assert_eq!(testTime.to_string(), "167:55:04");
+    }
+
+    #[test]
+    fn setTime_Correct() {
+        let mut testTime = PlayTime::new();
+
+        let timeResult = testTime.setTime(255, 59, 59, 59);
+
+        assert!(timeResult.is_ok());
+        assert_eq!(testTime.getHours(), &255);
+        assert_eq!(testTime.getFrames(), &59);
+    }
+
+    #[test]
+    fn setTime_IncorrectMinutes() {
+        let mut testTime = PlayTime::new();
+
+        let timeResult = testTime.setTime(10, 60, 0, 0);
+
+        assert!(timeResult.is_err());
+        assert_eq!(timeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Minutes \"60\" is over allowed maximum 59");
+        assert_eq!(testTime.getHours(), &0);
+    }
+
+    #[test]
+    fn setTime_IncorrectSeconds() {
+        let mut testTime = PlayTime::new();
+
+        let timeResult = testTime.setTime(10, 0, 60, 0);
+
+        assert!(timeResult.is_err());
+        assert_eq!(timeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Seconds \"60\" is over allowed maximum 59");
+    }
+
+    #[test]
+    fn setTime_IncorrectFrames() {
+        let mut testTime = PlayTime::new();
+
+        let timeResult = testTime.setTime(10, 0, 0, 60);
+
+        assert!(timeResult.is_err());
+        assert_eq!(timeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Frames \"60\" is over allowed maximum 59");
+    }
+
+    #[test]
+    fn setMaxed_Correct() {
+        let mut testTime = PlayTime::new();
+
+        testTime.setMaxed(true);
+        assert_eq!(testTime.isMaxed(), true);
+        assert_eq!(testTime.getMaxedFlag(), &0xFF);
+
+        testTime.setMaxed(false);
+        assert_eq!(testTime.isMaxed(), false);
+    }
+}
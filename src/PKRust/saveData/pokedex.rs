@@ -0,0 +1,180 @@
+use super::super::creatureData::pokemonSpecies::Species;
+
+/// Gen 1 has 151 Pokemon, which fit into 19 bytes of flags
+const POKEDEX_SIZE: usize = 151;
+pub const POKEDEX_BYTES: usize = 19;
+
+#[derive(Debug)]
+/**
+ * The players Pokedex progress
+ * owned: A bitfield of every Pokemon that has been caught, indexed by pokedex number
+ * seen: A bitfield of every Pokemon that has been seen, indexed by pokedex number
+ *
+ * More Info [here](https://bulbapedia.bulbagarden.net/wiki/Save_data_structure_(Generation_I)#Pok.C3.A9dex_owned)
+ */
+pub struct Pokedex {
+    owned: [u8; POKEDEX_BYTES],
+    seen: [u8; POKEDEX_BYTES]
+}
+impl Pokedex {
+    /// Constructor for a Pokedex, when being read from a save file
+    pub fn get(owned: [u8; POKEDEX_BYTES], seen: [u8; POKEDEX_BYTES]) -> Pokedex {
+        return Pokedex{owned, seen};
+    }
+
+    /// Returns an empty Pokedex
+    pub fn new() -> Pokedex {
+        return Pokedex{owned: [0; POKEDEX_BYTES], seen: [0; POKEDEX_BYTES]};
+    }
+
+    /// Returns the byte and the bit within it that a species is stored at.
+    ///
+    /// Pokedex number 1 is the lowest bit of the first byte.
+    fn getFlagPosition(species: &Species) -> (usize, u8) {
+        let dexIndex = (*species.getPokedex() - 1) as usize;
+        return (dexIndex / 8, (dexIndex % 8) as u8);
+    }
+
+    // ========   GETTERS   ========
+
+    /// Getter for the raw owned bitfield
+    pub fn getOwnedFlags(&self) -> &[u8; POKEDEX_BYTES] {
+        return &self.owned;
+    }
+
+    /// Getter for the raw seen bitfield
+    pub fn getSeenFlags(&self) -> &[u8; POKEDEX_BYTES] {
+        return &self.seen;
+    }
+
+    /// Returns whether a species has been caught
+    pub fn isOwned(&self, species: &Species) -> bool {
+        let (byte, bit) = Self::getFlagPosition(species);
+        return (self.owned[byte] >> bit) & 1 == 1;
+    }
+
+    /// Returns whether a species has been seen
+    pub fn isSeen(&self, species: &Species) -> bool {
+        let (byte, bit) = Self::getFlagPosition(species);
+        return (self.seen[byte] >> bit) & 1 == 1;
+    }
+
+    /// Returns how many species have been caught
+    pub fn getOwnedCount(&self) -> u32 {
+        return self.owned.iter().map(|byte| byte.count_ones()).sum();
+    }
+
+    /// Returns how many species have been seen
+    pub fn getSeenCount(&self) -> u32 {
+        return self.seen.iter().map(|byte| byte.count_ones()).sum();
+    }
+
+    // ========   SETTERS   ========
+
+    /// Marks a species as caught.
+    ///
+    /// A caught Pokemon has always been seen, so it is marked as seen as well.
+    pub fn setOwned(&mut self, species: &Species) {
+        let (byte, bit) = Self::getFlagPosition(species);
+        self.owned[byte] |= 1 << bit;
+        self.seen[byte] |= 1 << bit;
+    }
+
+    /// Marks a species as not caught.
+    ///
+    /// This does not change whether it has been seen.
+    pub fn clearOwned(&mut self, species: &Species) {
+        let (byte, bit) = Self::getFlagPosition(species);
+        self.owned[byte] &= !(1 << bit);
+    }
+
+    /// Marks a species as seen
+    pub fn setSeen(&mut self, species: &Species) {
+        let (byte, bit) = Self::getFlagPosition(species);
+        self.seen[byte] |= 1 << bit;
+    }
+
+    /// Marks a species as not seen.
+    ///
+    /// A Pokemon can't be caught without being seen, so it is marked as not caught as well.
+    pub fn clearSeen(&mut self, species: &Species) {
+        let (byte, bit) = Self::getFlagPosition(species);
+        self.seen[byte] &= !(1 << bit);
+        self.owned[byte] &= !(1 << bit);
+    }
+
+    /// Marks every species as both seen and caught
+    pub fn completeAll(&mut self) {
+        for dexIndex in 0..POKEDEX_SIZE {
+            self.owned[dexIndex / 8] |= 1 << (dexIndex % 8);
+            self.seen[dexIndex / 8] |= 1 << (dexIndex % 8);
+        }
+    }
+}
+
+#[cfg(test)]
+mod pokedexTests {
+    use super::*;
+
+    #[test]
+    fn setOwned_SetsSeen() {
+        let mut testDex = Pokedex::new();
+        // Bulbasaur, Pokedex number 1
+        let bulbasaur = Species::parse(0x99).unwrap();
+
+        testDex.setOwned(&bulbasaur);
+
+        assert_eq!(testDex.isOwned(&bulbasaur), true);
+        assert_eq!(testDex.isSeen(&bulbasaur), true);
+        assert_eq!(testDex.getOwnedFlags()[0], 0b0000_0001);
+    }
+
+    #[test]
+    fn setSeen_Correct() {
+        let mut testDex = Pokedex::new();
+        // Mew, Pokedex number 151
+        let mew = Species::parse(0x15).unwrap();
+
+        testDex.setSeen(&mew);
+
+        assert_eq!(testDex.isSeen(&mew), true);
+        assert_eq!(testDex.isOwned(&mew), false);
+        assert_eq!(testDex.getSeenFlags()[18], 0b0100_0000);
+    }
+
+    #[test]
+    fn clearSeen_ClearsOwned() {
+        let mut testDex = Pokedex::new();
+        let bulbasaur = Species::parse(0x99).unwrap();
+
+        testDex.setOwned(&bulbasaur);
+        testDex.clearSeen(&bulbasaur);
+
+        assert_eq!(testDex.isOwned(&bulbasaur), false);
+        assert_eq!(testDex.isSeen(&bulbasaur), false);
+    }
+
+    #[test]
+    fn clearOwned_KeepsSeen() {
+        let mut testDex = Pokedex::new();
+        let bulbasaur = Species::parse(0x99).unwrap();
+
+        testDex.setOwned(&bulbasaur);
+        testDex.clearOwned(&bulbasaur);
+
+        assert_eq!(testDex.isOwned(&bulbasaur), false);
+        assert_eq!(testDex.isSeen(&bulbasaur), true);
+    }
+
+    #[test]
+    fn completeAll_Correct() {
+        let mut testDex = Pokedex::new();
+
+        testDex.completeAll();
+
+        assert_eq!(testDex.getOwnedCount(), 151);
+        assert_eq!(testDex.getSeenCount(), 151);
+        // The last byte only has 7 used bits
+        assert_eq!(testDex.getOwnedFlags()[18], 0b0111_1111);
+    }
+}
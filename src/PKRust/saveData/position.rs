@@ -0,0 +1,297 @@
+use std::fs;
+use super::super::utils::formatError;
+
+/// Address of wOverworldMap in WRAM, which the view pointer points into
+const OVERWORLD_MAP_ADDR: u16 = 0xC6E8;
+
+/// Towns and routes come before every indoor map, starting at Red's House 1F
+const FIRST_INDOOR_MAP: u8 = 0x25;
+
+#[derive(Debug, Clone)]
+/**
+ * A map in the game world
+ * index: The index of the map in Gen 1 (0x00 being Pallet Town)
+ * name: The string name of the map
+ * width, height: The size of the map in blocks, where each block is 2x2 tiles
+ */
+pub struct Map {
+    index: u8,
+    name: String,
+    width: u8,
+    height: u8
+}
+impl Map {
+    /// Constructor for a Map, given an input map index
+    pub fn get(index: u8) -> Result<Map, String> {
+        let mapFile = fs::read_to_string("./data/maps.pkmn").unwrap();
+        let hexIndex = format!("0x{:02X}", index);
+        let mut mapLine: &str = "No Map found";
+
+        for line in mapFile.lines() {
+            if line.split(' ').next() == Some(hexIndex.as_str()) {
+                mapLine = line;
+                break;
+            }
+        }
+
+        let parsedMap: Vec<&str> = mapLine.split(' ').collect();
+        if parsedMap[0] != hexIndex {
+            return Err(formatError(format!("Map with ID {hexIndex} not found.")));
+        }
+        let name = parsedMap[1].replace('+', " ");
+        let width: u8 = parsedMap[2].parse().unwrap();
+        let height: u8 = parsedMap[3].parse().unwrap();
+
+        return Ok(Map{index, name, width, height});
+    }
+
+    /// Constructor for a map that isn't in the map list, such as an unused map
+    pub fn unknown(index: u8) -> Map {
+        return Map{index, name: String::from("Unknown"), width: 0, height: 0};
+    }
+
+    pub fn getIndex(&self) -> &u8 {
+        return &self.index;
+    }
+
+    pub fn getName(&self) -> &String {
+        return &self.name;
+    }
+
+    pub fn getWidth(&self) -> &u8 {
+        return &self.width;
+    }
+
+    pub fn getHeight(&self) -> &u8 {
+        return &self.height;
+    }
+}
+
+#[derive(Debug)]
+/**
+ * Where the player is standing, and where they will return to
+ * map: The map the player is on
+ * x, y: The players coordinates on the map, in tiles
+ * xBlock, yBlock: Which tile of the current 2x2 block the player is on
+ * viewPointer: A pointer into wOverworldMap for the top left block on screen
+ * lastMap: The last outdoor map, used by warps that lead "outside"
+ * lastBlackoutMap: The map the player returns to after blacking out
+ *
+ * More Info [here](https://github.com/pret/pokered/blob/master/ram/wram.asm)
+ */
+pub struct Position {
+    map: Map,
+    x: u8,
+    y: u8,
+    xBlock: u8,
+    yBlock: u8,
+    viewPointer: u16,
+    lastMap: Map,
+    lastBlackoutMap: Map
+}
+impl Position {
+    /// Constructor for Position, when being read from a save file
+    #[allow(clippy::too_many_arguments)]
+    pub fn get(map: Map, x: u8, y: u8, xBlock: u8, yBlock: u8, viewPointer: u16, lastMap: Map, lastBlackoutMap: Map) -> Position {
+        return Position{map, x, y, xBlock, yBlock, viewPointer, lastMap, lastBlackoutMap};
+    }
+
+    /// Returns the player standing in their bedroom, as in a new game
+    pub fn new() -> Position {
+        let bedroom = Map::get(0x26).unwrap();
+        let viewPointer = Self::calculateViewPointer(&bedroom, 3, 6);
+
+        return Position{map: bedroom, x: 3, y: 6, xBlock: 1, yBlock: 0, viewPointer, lastMap: Map::get(0x00).unwrap(), lastBlackoutMap: Map::get(0x00).unwrap()};
+    }
+
+    /// Returns a string with the players map and coordinates
+    pub fn to_string(&self) -> String {
+        return format!("{} (X: {}, Y: {})", self.map.getName(), self.x, self.y);
+    }
+
+    /// Calculates the view pointer for a position on a map.
+    ///
+    /// The overworld map has a 3 block border around it, and the top left block on screen
+    /// is 2 blocks up and to the left of the player, as done by the `event_displacement` macro.
+    fn calculateViewPointer(map: &Map, x: u8, y: u8) -> u16 {
+        let width = *map.getWidth() as u16;
+        return OVERWORLD_MAP_ADDR + 7 + width + ((width + 6) * (y as u16 >> 1)) + (x as u16 >> 1);
+    }
+
+    // ========   GETTERS   ========
+
+    pub fn getMap(&self) -> &Map {
+        return &self.map;
+    }
+
+    pub fn getX(&self) -> &u8 {
+        return &self.x;
+    }
+
+    pub fn getY(&self) -> &u8 {
+        return &self.y;
+    }
+
+    pub fn getXBlock(&self) -> &u8 {
+        return &self.xBlock;
+    }
+
+    pub fn getYBlock(&self) -> &u8 {
+        return &self.yBlock;
+    }
+
+    pub fn getViewPointer(&self) -> &u16 {
+        return &self.viewPointer;
+    }
+
+    pub fn getLastMap(&self) -> &Map {
+        return &self.lastMap;
+    }
+
+    pub fn getLastBlackoutMap(&self) -> &Map {
+        return &self.lastBlackoutMap;
+    }
+
+    // ========   SETTERS   ========
+
+    /// Moves the player to a new map and coordinates.
+    ///
+    /// The block coordinates and view pointer are derived from the new position,
+    /// so that the game loads the map around the player correctly.
+    /// Moving to a town or route also makes it the last map, as walking onto it would.
+    ///
+    /// The copy of the map header in the save (tileset, size, connections, warps, signs
+    /// and sprites) and the map's script progress are left as they were, for the game
+    /// to reload when it enters the map.
+    pub fn teleport(&mut self, mapIndex: u8, x: u8, y: u8) -> Result<bool, String> {
+        let map = Map::get(mapIndex)?;
+
+        // Each block is 2 tiles wide, so the coordinates can be up to double the map size
+        if x as u16 >= *map.getWidth() as u16 * 2 {
+            return Err(formatError(format!("X coordinate \"{}\" is outside of {}, which is {} tiles wide", x, map.getName(), *map.getWidth() as u16 * 2)));
+        } else if y as u16 >= *map.getHeight() as u16 * 2 {
+            return Err(formatError(format!("Y coordinate \"{}\" is outside of {}, which is {} tiles high", y, map.getName(), *map.getHeight() as u16 * 2)));
+        }
+
+        self.viewPointer = Self::calculateViewPointer(&map, x, y);
+        self.map = map;
+        self.x = x;
+        self.y = y;
+        self.xBlock = x & 1;
+        self.yBlock = y & 1;
+
+        if mapIndex < FIRST_INDOOR_MAP {
+            self.lastMap = self.map.clone();
+        }
+
+        return Ok(true);
+    }
+
+    /// Setter for the last outdoor map, which warps that lead "outside" go to
+    pub fn setLastMap(&mut self, mapIndex: u8) -> Result<bool, String> {
+        self.lastMap = Map::get(mapIndex)?;
+        return Ok(true);
+    }
+
+    /// Setter for the map the player returns to after blacking out
+    pub fn setLastBlackoutMap(&mut self, mapIndex: u8) -> Result<bool, String> {
+        self.lastBlackoutMap = Map::get(mapIndex)?;
+        return Ok(true);
+    }
+}
+
+#[cfg(test)]
+mod mapTests {
+    use super::*;
+
+    #[test]
+    fn get_Correct() {
+        let testMap = Map::get(0x00).unwrap();
+
+        assert_eq!(testMap.getName(), "Pallet Town");
+        assert_eq!(testMap.getWidth(), &10);
+        assert_eq!(testMap.getHeight(), &9);
+    }
+
+    #[test]
+    fn get_Incorrect() {
+        let mapResult = Map::get(0x0B);
+
+        assert!(mapResult.is_err());
+        assert_eq!(mapResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Map with ID 0x0B not found.");
+    }
+}
+
+#[cfg(test)]
+mod positionTests {
+    use super::*;
+
+    #[test]
+    fn new_ViewPointer() {
+        // Matches the view pointer of a save standing at the same spot
+        let testPosition = Position::new();
+
+        assert_eq!(testPosition.getViewPointer(), &0xC712);
+    }
+
+    #[test]
+    fn teleport_Correct() {
+        let mut testPosition = Position::new();
+
+        let teleportResult = testPosition.teleport(0x00, 5, 7);
+
+        assert!(teleportResult.is_ok());
+        assert_eq!(testPosition.getMap().getName(), "Pallet Town");
+        assert_eq!(testPosition.getXBlock(), &1);
+        assert_eq!(testPosition.getYBlock(), &1);
+        // 0xC6E8 + 7 + 10 + (16 * 3) + 2
+        assert_eq!(testPosition.getViewPointer(), &0xC72B);
+    }
+
+    #[test]
+    fn teleport_LastMap() {
+        let mut testPosition = Position::new();
+
+        // Routes are outdoors, so they become the last map
+        testPosition.teleport(0x0C, 4, 4).unwrap();
+        assert_eq!(testPosition.getLastMap().getName(), "Route 1");
+
+        // Indoor maps keep the last outdoor map the player was on
+        testPosition.teleport(0x27, 2, 2).unwrap();
+        assert_eq!(testPosition.getLastMap().getName(), "Route 1");
+
+        testPosition.setLastMap(0x01).unwrap();
+        assert_eq!(testPosition.getLastMap().getName(), "Viridian City");
+    }
+
+    #[test]
+    fn teleport_IncorrectMap() {
+        let mut testPosition = Position::new();
+
+        let teleportResult = testPosition.teleport(0x0B, 0, 0);
+
+        assert!(teleportResult.is_err());
+        assert_eq!(testPosition.getMap().getIndex(), &0x26);
+    }
+
+    #[test]
+    fn teleport_IncorrectX() {
+        let mut testPosition = Position::new();
+
+        let teleportResult = testPosition.teleport(0x00, 20, 0);
+
+        assert!(teleportResult.is_err());
+        assert_eq!(teleportResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: X coordinate \"20\" is outside of Pallet Town, which is 20 tiles wide");
+        assert_eq!(testPosition.getX(), &3);
+    }
+
+    #[test]
+    fn teleport_IncorrectY() {
+        let mut testPosition = Position::new();
+
+        let teleportResult = testPosition.teleport(0x26, 0, 8);
+
+        assert!(teleportResult.is_err());
+        assert_eq!(teleportResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Y coordinate \"8\" is outside of Red's House 2F, which is 8 tiles high");
+    }
+}
@@ -3,32 +3,124 @@ use std::path::PathBuf;
 
 use super::creatureData::pokemonMove::Move;
 use super::creatureData::pokemon::*;
+use super::creatureData::pokemonSpecies::Species;
+use super::creatureData::pokemonStatusCondition::StatusCondition;
+use super::saveData::item::{Item, Inventory};
+use super::saveData::pokedex::{Pokedex, POKEDEX_BYTES};
+use super::saveData::badges::{Badge, Badges};
+use super::saveData::playTime::PlayTime;
+use super::saveData::options::{Options, BattleStyle};
+use super::saveData::hallOfFame::{HallOfFame, HallOfFameEntry, HOF_MAX_RECORDS};
+use super::saveData::position::{Position, Map};
+use super::saveData::eventFlags::{EventFlags, FlagKind, EVENT_FLAG_BYTES, MISSABLE_BYTES};
+use super::saveData::gameVersion::{GameVersion, PIKACHU_INDEX};
 use super::addresses::*;
-use super::utils::{textDecode, integrityCheck, formatError};
-
+use super::utils::{textDecode, textEncode, kanaDecode, kanaEncode, detectLayout, repairChecksums, formatError, TEXT_TERMINATOR};
+use super::utils::bcd;
+use super::utils::container::SaveContainer;
+
+
+/// The parts of a save that can be edited through `Save`.
+/// 
+/// Only regions that have been edited are written back, so
+/// anything left untouched stays byte-for-byte the same.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Region {
+    TrainerName,
+    RivalName,
+    Money,
+    Coins,
+    TrainerID,
+    Party,
+    Bag,
+    PCItems,
+    Pokedex,
+    Badges,
+    PlayTime,
+    Options,
+    HallOfFame,
+    Position,
+    EventFlags,
+    Daycare,
+    PCBoxes,
+    PikachuFriendship,
+}
 
 #[derive(Debug)]
 pub struct Save {
     trainer: String,
+    rival: String,
     money: u32,
+    /// Game Corner coins
+    coins: u16,
     id: u16,
     party: Vec<Pokemon>,
 
-    /// Each save file has 12 boxes, which hold 20 pokemon each.
+    /// Each save file has 12 boxes, which hold 20 pokemon each
+    /// (8 boxes of 30 in Japanese saves).
     /// 
     /// More Info [here](https://bulbapedia.bulbagarden.net/wiki/Pok%C3%A9mon_Storage_System)
-    pc: Vec<Vec<Pokemon>>
+    pc: Vec<Vec<Pokemon>>,
+    /// The index of the box that is open in the PC
+    currentBox: usize,
+
+    /// The bag holds up to 20 items, and the PC holds up to 50
+    bag: Inventory,
+    pcItems: Inventory,
+
+    pokedex: Pokedex,
+    badges: Badges,
+    playTime: PlayTime,
+    options: Options,
+    hallOfFame: HallOfFame,
+    position: Position,
+    eventFlags: EventFlags,
+    daycare: Option<Pokemon>,
+    /// The friendship of the starter Pikachu, which is only used in Yellow
+    pikachuFriendship: u8,
+
+    /// The layout of the save, which depends on the region of the game
+    layout: &'static SaveLayout,
+    /// The version of the game, which is guessed when loading but can be overridden
+    version: GameVersion,
+    /// The format of the file the save was read from, which is kept when writing
+    container: SaveContainer,
+    /// The original save image, which edits are patched onto when writing
+    data: Vec<u8>,
+    /// The regions that have been edited since the save was loaded
+    dirty: Vec<Region>
 }
 
 #[allow(dead_code)]
 impl Save {
 
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Save {
         return Save{    trainer: String::from("Null"),
+                        rival: String::from("Null"),
                         money: 0,
+                        coins: 0,
                         id: 0,
                         party: vec![Pokemon::new()],
-                        pc: Vec::new()
+                        pc: Vec::new(),
+                        currentBox: 0,
+                        bag: Inventory::new(BAG_CAPACITY),
+                        pcItems: Inventory::new(PC_ITEMS_CAPACITY),
+                        pokedex: Pokedex::new(),
+                        badges: Badges::get(0),
+                        playTime: PlayTime::new(),
+                        // Medium text speed, with animations on and shift style, as in a new game
+                        options: Options::get(0x03),
+                        hallOfFame: HallOfFame::new(),
+                        position: Position::new(),
+                        eventFlags: EventFlags::new(),
+                        daycare: None,
+                        pikachuFriendship: 0,
+                        layout: &INTERNATIONAL,
+                        version: GameVersion::RedBlue,
+                        container: SaveContainer::new(),
+                        data: vec![0; SAVE_SIZE],
+                        dirty: Vec::new()
                     }
     } 
 
@@ -40,7 +132,7 @@ impl Save {
 
         // First we load the save file and check for if it exists
         // If not, an error result will be returned
-        let save = match fs::read(filePathBuf) {
+        let saveFile = match fs::read(filePathBuf) {
             Ok(result)                => result,
             Err(error)                  => match error.kind() {
                 std::io::ErrorKind::NotFound   => return Err(formatError(format!("Save \"{}\" does not exist",file))),
@@ -48,20 +140,112 @@ impl Save {
             }
         };
 
+        return Self::from_bytes(saveFile);
+    }
+
+    /// Loads a save from the bytes of a save file, in any of the formats `load` accepts
+    pub fn from_bytes(saveFile: Vec<u8>) -> Result<Save, String> {
+
+        // Emulators and dumpers store the SRAM in slightly different files, so it is taken out first
+        let (container, save) = SaveContainer::detect(&saveFile).map_err(formatError)?;
+
         // Then we check if the file has integrity (Check if it's valid)
-        if integrityCheck(&save) == false {
-            return Err(formatError(String::from("File does not seem to be a Gen 1 Save File")));
+        // The checksum is in a different place in Japanese saves, which is how the layout is found
+        let layout = match detectLayout(&save) {
+            Some(layout)    => layout,
+            None            => return Err(formatError(String::from("File does not seem to be a Gen 1 Save File")))
+        };
+
+        let pc = Self::getPCBoxesFromSave(&save, layout);
+        let currentBox = Self::getCurrentBoxFromSave(&save, layout);
+
+        // Corrupted money and coins are loaded as 0, so that the save can still be opened and repaired.
+        // Their bytes are kept as they were until they are set, and checkMoney/checkCoins report them.
+        let money = Self::getMoneyFromSave(&save, layout).unwrap_or(0);
+        let coins = Self::getCoinsFromSave(&save, layout).unwrap_or(0);
+        let id = Self::getTrainerIDFromSave(&save, layout);
+        let party:  Vec<Pokemon> = Self::getPartyFromSave(&save, layout);
+        let trainer = Self::decodeName(layout, &Self::getNameFromSave(&save, layout));
+        let rival = Self::decodeName(layout, &Self::getRivalNameFromSave(&save, layout));
+        let bag = Inventory::get(Self::getItemsFromSave(&save, &layout.bagAddr, BAG_CAPACITY), BAG_CAPACITY);
+        let pcItems = Inventory::get(Self::getItemsFromSave(&save, &layout.pcItemsAddr, PC_ITEMS_CAPACITY), PC_ITEMS_CAPACITY);
+        let pokedex = Self::getPokedexFromSave(&save, layout);
+        let badges = Badges::get(save[layout.badgesAddr]);
+        let playTime = Self::getPlayTimeFromSave(&save, layout);
+        let options = Options::get(save[layout.optionsAddr]);
+        let hallOfFame = Self::getHallOfFameFromSave(&save, layout);
+        let position = Self::getPositionFromSave(&save, layout);
+        let eventFlags = Self::getEventFlagsFromSave(&save, layout);
+        let daycare = Self::getDaycareFromSave(&save, layout);
+        let pikachuFriendship = save[layout.pikachuFriendshipAddr];
+        let version = GameVersion::detect(&save, layout);
+
+        return Ok(Save{trainer, rival, money, coins, id, party, pc, currentBox, bag, pcItems, pokedex, badges, playTime, options, hallOfFame, position, eventFlags, daycare, pikachuFriendship, layout, version, container, data: save, dirty: Vec::new()});
+
+    }
+
+    /// Writes the save, with all edits applied, to the given path
+    pub fn write(&self, file: &str) -> Result<bool, String> {
+
+        let filePathBuf:PathBuf = std::path::PathBuf::from(file);
+
+        match fs::write(filePathBuf, self.to_bytes()?) {
+            Ok(_)       => return Ok(true),
+            Err(error)  => return Err(formatError(format!("Could not write save \"{}\": {}", file, error.kind())))
+        }
+
+    }
+
+    /// Returns the save image with all edits applied, and all checksums recalculated.
+    /// 
+    /// Only edited regions are written, so anything not modelled by `Save`
+    /// (or not edited) is kept as it was in the original image.
+    /// A save with no edits is returned exactly as it was loaded, in the same file format.
+    /// 
+    /// Returns an error if a name can't be written in the saves character set,
+    /// such as a Pokemon from an international save put into a Japanese one.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        let mut save = self.data.clone();
+
+        if self.dirty.is_empty() {
+            return Ok(self.container.wrap(&save));
         }
 
-        let pc = Self::getPCBoxesFromSave(&save);
+        let layout = self.layout;
+
+        for region in &self.dirty {
+            match region {
+                Region::TrainerName => { Self::writeNameToSave(&mut save, layout, &layout.nameAddr, &self.trainer, &None)?; },
+                Region::RivalName   => { Self::writeNameToSave(&mut save, layout, &layout.rivalAddr, &self.rival, &None)?; },
+                Region::Money       => Self::writeMoneyToSave(&mut save, layout, &self.money),
+                Region::Coins       => Self::writeCoinsToSave(&mut save, layout, &self.coins),
+                Region::TrainerID   => Self::writeTrainerIDToSave(&mut save, layout, &self.id),
+                Region::Party       => { Self::writePartyToSave(&mut save, layout, &self.party)?; },
+                Region::Bag         => Self::writeItemsToSave(&mut save, &layout.bagAddr, &self.bag),
+                Region::PCItems     => Self::writeItemsToSave(&mut save, &layout.pcItemsAddr, &self.pcItems),
+                Region::Pokedex     => Self::writePokedexToSave(&mut save, layout, &self.pokedex),
+                Region::Badges      => save[layout.badgesAddr] = *self.badges.getFlags(),
+                Region::PlayTime    => Self::writePlayTimeToSave(&mut save, layout, &self.playTime),
+                Region::Options     => save[layout.optionsAddr] = *self.options.getFlags(),
+                Region::HallOfFame  => { Self::writeHallOfFameToSave(&mut save, layout, &self.hallOfFame)?; },
+                Region::Position    => Self::writePositionToSave(&mut save, layout, &self.position),
+                Region::EventFlags  => Self::writeEventFlagsToSave(&mut save, layout, &self.eventFlags),
+                Region::Daycare     => { Self::writeDaycareToSave(&mut save, layout, &self.daycare)?; },
+                Region::PCBoxes     => { Self::writePCBoxesToSave(&mut save, layout, &self.pc, &self.currentBox)?; },
+                Region::PikachuFriendship => save[layout.pikachuFriendshipAddr] = self.pikachuFriendship,
+            }
+        }
 
-        let money = Self::getMoneyFromSave(&save);
-        let id = Self::getTrainerIDFromSave(&save);
-        let party:  Vec<Pokemon> = Self::getPartyFromSave(&save);
-        let trainer = textDecode(&Self::getNameFromSave(&save));
+        repairChecksums(&mut save, layout);
 
-        return Ok(Save{trainer, money, id, party, pc});
+        return Ok(self.container.wrap(&save));
+    }
 
+    /// Marks a region as edited, so that it is written back by `to_bytes`
+    fn markDirty(&mut self, region: Region) {
+        if !self.dirty.contains(&region) {
+            self.dirty.push(region);
+        }
     }
 
     pub fn to_string(&self) -> String {
@@ -71,13 +255,56 @@ impl Save {
     /// Print the save file data to terminal
     pub fn print(&self) {
         println!("\n=== Save Info ===");
+        println!("Game: {}", self.version.getName());
+        println!("Format: {}", self.container.to_string());
         println!("Name: {}\nPlayer ID: {}\nMoney: {}",self.trainer, self.id, self.money);
+        if let Err(error) = self.checkMoney() {
+            println!("{}", error);
+        }
+        println!("Rival: {}", self.rival);
+        println!("Coins: {}", self.coins);
+        if let Err(error) = self.checkCoins() {
+            println!("{}", error);
+        }
+        println!("Pokedex Owned: {} Seen: {}", self.pokedex.getOwnedCount(), self.pokedex.getSeenCount());
+        println!("Badges ({}): {}", self.badges.getCount(), self.badges.to_string());
+        println!("Play Time: {}", self.playTime.to_string());
+        println!("{}", self.options.to_string());
+        println!("Position: {}", self.position.to_string());
+        if self.version.isYellow() {
+            println!("Pikachu Friendship: {}", self.pikachuFriendship);
+        }
+        println!("=================");
+
+        println!("\n=== Bag ===");
+        print!("{}", self.bag.to_string());
         println!("=================");
 
         println!("\n=== Party ===");
-        for pokemon in 0..5 {
-            println!("{}",&self.party[pokemon].getDetails());
+        for pokemon in &self.party {
+            println!("{}",pokemon.getDetails());
+            // Edited saves can have a level that doesn't match the experience
+            if let Err(error) = pokemon.checkExp() {
+                println!("{}", error);
+            }
+            // The catch rate isn't checked, as evolving doesn't change it
+            if let Err(error) = pokemon.checkTypes() {
+                println!("{}", error);
+            }
+            if let Err(error) = pokemon.checkStatus() {
+                println!("{}", error);
+            }
+        }
+        println!("=================\n");
+
+        if let Some(pokemon) = &self.daycare {
+            println!("=== Daycare ===");
+            println!("{}", pokemon.getDetails());
+            println!("=================\n");
         }
+
+        println!("=== Hall of Fame ({} entered) ===", self.hallOfFame.getCount());
+        print!("{}", self.hallOfFame.to_string());
         println!("=================\n");
     }
 
@@ -88,11 +315,47 @@ impl Save {
         return &self.trainer;
     }    
 
+    /// Getter for Rival Name in Save
+    pub fn getRivalName(&self) -> &String {
+        return &self.rival;
+    }
+
     /// Getter for Money in Save
     pub fn getMoney(&self) -> &u32 {
         return &self.money;
     }
 
+    /// Getter for Game Corner Coins in Save
+    pub fn getCoins(&self) -> &u16 {
+        return &self.coins;
+    }
+
+    /// Checks that the money in the save is valid BCD.
+    /// 
+    /// Corrupted money is loaded as 0, and stays corrupted in the save until `setMoney` is used.
+    pub fn checkMoney(&self) -> Result<bool, String> {
+        if self.dirty.contains(&Region::Money) {
+            return Ok(true);
+        }
+
+        Self::getMoneyFromSave(&self.data, self.layout).map_err(|error| formatError(format!("Money is corrupted: {}", error.to_string())))?;
+
+        return Ok(true);
+    }
+
+    /// Checks that the Game Corner coins in the save are valid BCD.
+    /// 
+    /// Corrupted coins are loaded as 0, and stay corrupted in the save until `setCoins` is used.
+    pub fn checkCoins(&self) -> Result<bool, String> {
+        if self.dirty.contains(&Region::Coins) {
+            return Ok(true);
+        }
+
+        Self::getCoinsFromSave(&self.data, self.layout).map_err(|error| formatError(format!("Coins are corrupted: {}", error.to_string())))?;
+
+        return Ok(true);
+    }
+
     /// Getter for Trainer ID in Save
     pub fn getTrainerID(&self) -> &u16 {
         return &self.id;
@@ -108,24 +371,187 @@ impl Save {
         return &self.pc;
     }
 
+    /// Getter for the index of the box that is open in the PC
+    pub fn getCurrentBox(&self) -> &usize {
+        return &self.currentBox;
+    }
+
+    /// Getter for the items in the Bag
+    pub fn getBag(&self) -> &Inventory {
+        return &self.bag;
+    }
+
+    /// Getter for the items stored in the PC
+    pub fn getPCItems(&self) -> &Inventory {
+        return &self.pcItems;
+    }
+
+    /// Getter for the Pokedex
+    pub fn getPokedex(&self) -> &Pokedex {
+        return &self.pokedex;
+    }
+
+    /// Getter for the Gym Badges
+    pub fn getBadges(&self) -> &Badges {
+        return &self.badges;
+    }
+
+    /// Getter for the Play Time
+    pub fn getPlayTime(&self) -> &PlayTime {
+        return &self.playTime;
+    }
+
+    /// Getter for the Options
+    pub fn getOptions(&self) -> &Options {
+        return &self.options;
+    }
+
+    /// Getter for the Hall of Fame
+    pub fn getHallOfFame(&self) -> &HallOfFame {
+        return &self.hallOfFame;
+    }
+
+    /// Getter for the players position
+    pub fn getPosition(&self) -> &Position {
+        return &self.position;
+    }
+
+    /// Getter for the event flags and missable objects
+    pub fn getEventFlags(&self) -> &EventFlags {
+        return &self.eventFlags;
+    }
+
+    /// Returns whether a named event flag or missable object is set
+    /// 
+    /// This is an abstraction for eventFlags::EventFlags::isSetByName
+    pub fn isFlagSet(&self, name: &str) -> Result<bool, String> {
+        EventFlags::checkFlagVersion(name, &self.version)?;
+        return self.eventFlags.isSetByName(name);
+    }
+
+    /// Getter for the Pokemon in the daycare, if there is one
+    pub fn getDaycare(&self) -> &Option<Pokemon> {
+        return &self.daycare;
+    }
+
+    /// Getter for the friendship of the starter Pikachu, in Yellow
+    pub fn getPikachuFriendship(&self) -> Result<&u8, String> {
+        self.checkYellow()?;
+
+        return Ok(&self.pikachuFriendship);
+    }
+
+    /// Returns the party slot of the players starter Pikachu, in Yellow
+    /// 
+    /// The game doesn't store which Pikachu is the starter (or which one follows the player).
+    /// Instead it takes the first Pikachu in the party with the players ID and name as its Original Trainer,
+    /// so a traded Pikachu or one with a changed OT is never the starter.
+    pub fn getStarterPikachu(&self) -> Result<usize, String> {
+        self.checkYellow()?;
+
+        let mut mismatch: Option<String> = None;
+
+        for (slot, pokemon) in self.party.iter().enumerate() {
+            if *pokemon.getSpecies().getIndex() != PIKACHU_INDEX {
+                continue;
+            }
+
+            if *pokemon.getOTID() != self.id {
+                mismatch.get_or_insert(format!("The Pikachu in party slot {} isn't the players starter, as its Original Trainer ID {} doesn't match the players ID {}", slot, pokemon.getOTID(), self.id));
+            } else if *pokemon.getOTN() != self.trainer {
+                mismatch.get_or_insert(format!("The Pikachu in party slot {} isn't the players starter, as its Original Trainer \"{}\" doesn't match the players name \"{}\"", slot, pokemon.getOTN(), self.trainer));
+            } else {
+                return Ok(slot);
+            }
+        }
+
+        return match mismatch {
+            Some(reason)    => Err(formatError(reason)),
+            None            => Err(formatError(String::from("There is no Pikachu in the party")))
+        };
+    }
+
+    /// Checks that the save is from Yellow, for features that only Yellow has
+    fn checkYellow(&self) -> Result<bool, String> {
+        if !self.version.isYellow() {
+            return Err(formatError(format!("Pikachu's friendship is only in Yellow, but this save is from {}", self.version.getName())));
+        }
+
+        return Ok(true);
+    }
+
+    /// Getter for the regions that have been edited since loading
+    pub fn getDirtyRegions(&self) -> &Vec<Region> {
+        return &self.dirty;
+    }
+
+    /// Getter for the region of the game the save is from
+    pub fn getGameRegion(&self) -> &GameRegion {
+        return &self.layout.region;
+    }
+
+    /// Getter for the version of the game the save is from
+    pub fn getGameVersion(&self) -> &GameVersion {
+        return &self.version;
+    }
+
+    /// Getter for the format of the file the save was read from
+    pub fn getContainer(&self) -> &SaveContainer {
+        return &self.container;
+    }
+
     // ========   SETTERS   ========
 
     /// Setter for Trainer Name in Save
     pub fn setTrainerName(&mut self, name: String) -> Result<bool, String> {
 
         // First let's check that the length is correct.
-        if name.len() > 7 {
-            return Err(formatError(format!("Name \"{}\" is over 7 characters.", name)));
+        if name.chars().count() > self.layout.playerNameLength {
+            return Err(formatError(format!("Name \"{}\" is over {} characters.", name, self.layout.playerNameLength)));
         }
+        Self::encodeName(self.layout, &name)?;
 
         // Now that the check is over, set the name
         self.trainer = name;
+        self.markDirty(Region::TrainerName);
 
         // And return True
         return Ok(true);
 
     }
 
+    /// Setter for Rival Name in Save
+    pub fn setRivalName(&mut self, name: String) -> Result<bool, String> {
+
+        // The rival name has the same 7 character limit as the players name
+        if name.chars().count() > self.layout.playerNameLength {
+            return Err(formatError(format!("Rival name \"{}\" is over {} characters.", name, self.layout.playerNameLength)));
+        }
+        Self::encodeName(self.layout, &name)?;
+
+        // Now that the check is over, set the name
+        self.rival = name;
+        self.markDirty(Region::RivalName);
+
+        return Ok(true);
+
+    }
+
+    /// Overrides the version of the game that was detected when loading
+    /// 
+    /// The version isn't stored in the save, so this only changes how the save is treated.
+    /// It can't change the region, as that decides the layout of the save.
+    pub fn setGameVersion(&mut self, version: GameVersion) -> Result<bool, String> {
+
+        if version.getRegion() != self.layout.region {
+            return Err(formatError(format!("Game version \"{}\" can't be used for a {:?} save", version.getName(), self.layout.region)));
+        }
+
+        self.version = version;
+
+        return Ok(true);
+    }
+
     /// Setter for Money amount in Save
     pub fn setMoney(&mut self, amount: u32) -> Result<bool, String> {
 
@@ -139,12 +565,28 @@ impl Save {
 
         // Now that the check is over, set the name
         self.money = amount;
+        self.markDirty(Region::Money);
 
         // And return True
         return Ok(true);
 
     }
     
+    /// Setter for Game Corner Coins in Save
+    pub fn setCoins(&mut self, amount: u16) -> Result<bool, String> {
+
+        // The Coin Case can only hold 9,999 coins
+        if amount > 9_999 {
+            return Err(formatError(format!("Amount \"{}\" is over allowed maximum 9,999.", amount)));
+        }
+
+        self.coins = amount;
+        self.markDirty(Region::Coins);
+
+        return Ok(true);
+
+    }
+
     /// Setter for Trainer ID
     /// 
     /// **Note**: There is no validation in this function.
@@ -156,634 +598,3113 @@ impl Save {
         // No checks can be done here because all is taken into account by the type.
         // Range validation should be done in the UI.
         self.id = newID;
+        self.markDirty(Region::TrainerID);
 
     }
 
-    /// Party Pokemon Setter for Nickname.
+    /// Adds an amount of an item to the Bag
     /// 
-    /// This is an abstraction for pokemon::Pokemon::setNickname
-    pub fn setPartyPokemonNick(&mut self, partyPokemon: usize, newNickname: String) -> Result<bool, String> {
-        
-        // First we check that there is a Pokemon in the party at the index
-        if partyPokemon > self.party.len() - 1 {
-            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
-        }
+    /// This is an abstraction for item::Inventory::addItem
+    pub fn addBagItem(&mut self, index: u8, quantity: u8) -> Result<bool, String> {
+        self.bag.addItem(index, quantity)?;
+        self.markDirty(Region::Bag);
 
-        // Then we update the nickname, and store the result for Error Handling
-        let nicknameChangeRes = self.party[partyPokemon].setNickname(newNickname);
+        return Ok(true);
+    }
 
-        // If the nickname change was unsuccessful, return the error
-        if nicknameChangeRes.is_err() {
-            return Err(nicknameChangeRes.unwrap_err());
-        } else {
-            return Ok(true);
-        }
-        
+    /// Removes an amount of an item from the Bag
+    /// 
+    /// This is an abstraction for item::Inventory::removeItem
+    pub fn removeBagItem(&mut self, index: u8, quantity: u8) -> Result<bool, String> {
+        self.bag.removeItem(index, quantity)?;
+        self.markDirty(Region::Bag);
+
+        return Ok(true);
     }
 
-    /// Party Pokemon Setter for Level
+    /// Sets the quantity of the item in a Bag slot
     /// 
-    /// This is an abstraction for pokemon::Pokemon::setLevel
-    pub fn setPartyPokemonLevel(&mut self, partyPokemon: usize, newLevel: i8) -> Result<bool, String> {
-        
-        // First we check that the party index is existing
-        if partyPokemon > self.party.len() - 1 {
-            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
-        }
+    /// This is an abstraction for item::Inventory::setQuantity
+    pub fn setBagItemQuantity(&mut self, slot: usize, quantity: u8) -> Result<bool, String> {
+        self.bag.setQuantity(slot, quantity)?;
+        self.markDirty(Region::Bag);
 
-        // Then we update the pokemons level and store the result
-        let levelChangeResult = self.party[partyPokemon].setLevel(newLevel);
-    
-        if levelChangeResult.is_err() {
-            return Err(levelChangeResult.unwrap_err());
-        } else {
-            return Ok(true);
-        }
+        return Ok(true);
     }
 
-    /// Party Pokemon Setter for OTID
+    /// Adds an amount of an item to the PC
     /// 
-    /// This is an abstraction for pokemon::Pokemon::setOTID
+    /// This is an abstraction for item::Inventory::addItem
+    pub fn addPCItem(&mut self, index: u8, quantity: u8) -> Result<bool, String> {
+        self.pcItems.addItem(index, quantity)?;
+        self.markDirty(Region::PCItems);
+
+        return Ok(true);
+    }
+
+    /// Removes an amount of an item from the PC
     /// 
-    /// **Note**: There is no range validation for the OTID in this function.
-    /// This is due to the fact that the typing (`u16`) provides the ranges for the value,
-    /// as [Pokemon Trainer ID's](https://bulbapedia.bulbagarden.net/wiki/Trainer_ID_number) in Gen 1
-    /// cannout be *under* 0 or *over* the 16-bit unsigned integer limit.
-    pub fn setPartyPokemonOTID(&mut self, partyPokemon: usize, newOTID: u16) -> Result<bool, String> {
+    /// This is an abstraction for item::Inventory::removeItem
+    pub fn removePCItem(&mut self, index: u8, quantity: u8) -> Result<bool, String> {
+        self.pcItems.removeItem(index, quantity)?;
+        self.markDirty(Region::PCItems);
 
-         // First we check that the party index is existing
-         if partyPokemon > self.party.len() - 1 {
-            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
-        }
+        return Ok(true);
+    }
 
-        // Then we edit the OT ID
-        self.party[partyPokemon].setOTID(newOTID);
+    /// Sets the quantity of the item in a PC slot
+    /// 
+    /// This is an abstraction for item::Inventory::setQuantity
+    pub fn setPCItemQuantity(&mut self, slot: usize, quantity: u8) -> Result<bool, String> {
+        self.pcItems.setQuantity(slot, quantity)?;
+        self.markDirty(Region::PCItems);
 
-        // And finally we return a successful result
         return Ok(true);
     }
 
-    /// Party Pokemon Setter for OT Nickname
+    /// Setter for whether a species has been caught in the Pokedex
     /// 
-    /// This is an abstraction for pokemon::Pokemon::setOTN
-    pub fn setPartyPokemonOTN(&mut self, partyPokemon: usize, newOTN: String) -> Result<bool, String> {
-        
-        // First we check that the party index is existing
-        if partyPokemon > self.party.len() - 1 {
-            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+    /// This is an abstraction for pokedex::Pokedex::setOwned and pokedex::Pokedex::clearOwned
+    pub fn setPokedexOwned(&mut self, speciesIndex: i16, owned: bool) -> Result<bool, String> {
+        let species = Species::parse(speciesIndex).map_err(formatError)?;
+
+        if owned {
+            self.pokedex.setOwned(&species);
+        } else {
+            self.pokedex.clearOwned(&species);
         }
+        self.markDirty(Region::Pokedex);
 
-        // Then we edit the OTN, and store the result
-        let changeOTNResult = self.party[partyPokemon].setOTN(newOTN);
+        return Ok(true);
+    }
 
-        // If we get an error, we return it
-        // Else, return a success
-        if changeOTNResult.is_err() {
-            return Err(changeOTNResult.unwrap_err());
+    /// Setter for whether a species has been seen in the Pokedex
+    /// 
+    /// This is an abstraction for pokedex::Pokedex::setSeen and pokedex::Pokedex::clearSeen
+    pub fn setPokedexSeen(&mut self, speciesIndex: i16, seen: bool) -> Result<bool, String> {
+        let species = Species::parse(speciesIndex).map_err(formatError)?;
+
+        if seen {
+            self.pokedex.setSeen(&species);
         } else {
-            return Ok(true);
+            self.pokedex.clearSeen(&species);
         }
+        self.markDirty(Region::Pokedex);
 
+        return Ok(true);
     }
 
-    /// Party Pokemon Setter for HP EV
+    /// Marks every species in the Pokedex as seen and caught
+    pub fn completePokedex(&mut self) {
+        self.pokedex.completeAll();
+        self.markDirty(Region::Pokedex);
+    }
+
+    /// Setter for whether a Gym Badge has been obtained
     /// 
-    /// Abstraction for pokemon::Pokemon::setEV_HP()
-    pub fn setPartyPokemonEV_HP(&mut self, partyPokemon: usize, newHP: u16) -> Result<bool, String> {
-        // First we check that the party index is existing
-        if partyPokemon > self.party.len() - 1 {
-            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
-        }
+    /// Badges are numbered 0-7, from the Boulder Badge to the Earth Badge.
+    pub fn setBadge(&mut self, badge: usize, obtained: bool) -> Result<bool, String> {
+        let badge = Badge::get(badge)?;
 
-        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
-        self.party[partyPokemon].setEV_HP(newHP);
+        self.badges.set(badge, obtained);
+        self.markDirty(Region::Badges);
 
-        // And return a success
         return Ok(true);
-    } 
+    }
 
-    /// Party Pokemon Setter for ATK EV
+    /// Setter for the Play Time
     /// 
-    /// Abstraction for pokemon::Pokemon::setEV_ATK()
-    pub fn setPartyPokemonEV_ATK(&mut self, partyPokemon: usize, newATK: u16) -> Result<bool, String> {
-        // First we check that the party index is existing
-        if partyPokemon > self.party.len() - 1 {
-            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
-        }
+    /// This is an abstraction for playTime::PlayTime::setTime
+    pub fn setPlayTime(&mut self, hours: u8, minutes: u8, seconds: u8, frames: u8) -> Result<bool, String> {
+        self.playTime.setTime(hours, minutes, seconds, frames)?;
+        self.markDirty(Region::PlayTime);
 
-        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
-        self.party[partyPokemon].setEV_ATK(newATK);
-
-        // And return a success
         return Ok(true);
-    } 
+    }
 
-    /// Party Pokemon Setter for DEF EV
+    /// Setter for whether the Play Time has stopped at its maximum
     /// 
-    /// Abstraction for pokemon::Pokemon::setEV_DEF()
-    pub fn setPartyPokemonEV_DEF(&mut self, partyPokemon: usize, newDEF: u16) -> Result<bool, String> {
-        // First we check that the party index is existing
-        if partyPokemon > self.party.len() - 1 {
-            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
-        }
+    /// This is an abstraction for playTime::PlayTime::setMaxed
+    pub fn setPlayTimeMaxed(&mut self, maxed: bool) {
+        self.playTime.setMaxed(maxed);
+        self.markDirty(Region::PlayTime);
+    }
 
-        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
-        self.party[partyPokemon].setEV_DEF(newDEF);
+    /// Setter for the Text Speed option
+    /// 
+    /// This is an abstraction for options::Options::setTextSpeed
+    pub fn setTextSpeed(&mut self, speed: u8) -> Result<bool, String> {
+        self.options.setTextSpeed(speed)?;
+        self.markDirty(Region::Options);
 
-        // And return a success
         return Ok(true);
-    } 
+    }
 
-    /// Party Pokemon Setter for SPD EV
+    /// Setter for the Battle Animation option
+    pub fn setBattleAnimation(&mut self, on: bool) {
+        self.options.setBattleAnimation(on);
+        self.markDirty(Region::Options);
+    }
+
+    /// Setter for the Battle Style option
+    pub fn setBattleStyle(&mut self, style: BattleStyle) {
+        self.options.setBattleStyle(style);
+        self.markDirty(Region::Options);
+    }
+
+    /// Checks that the Hall of Fame can be edited in the saves region
     /// 
-    /// Abstraction for pokemon::Pokemon::setEV_SPD()
-    pub fn setPartyPokemonEV_SPD(&mut self, partyPokemon: usize, newSPD: u16) -> Result<bool, String> {
-        // First we check that the party index is existing
-        if partyPokemon > self.party.len() - 1 {
-            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+    /// The Japanese Hall of Fame entries are only assumed to keep the international layout,
+    /// so they aren't written until that is confirmed.
+    fn checkHallOfFameEditable(&self) -> Result<bool, String> {
+        if self.layout.region == GameRegion::Japanese {
+            return Err(formatError(String::from("Editing the Hall of Fame isn't supported for Japanese saves, as the layout of its entries hasn't been confirmed")));
         }
 
-        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
-        self.party[partyPokemon].setEV_SPD(newSPD);
+        return Ok(true);
+    }
+
+    /// Removes every team from the Hall of Fame
+    pub fn clearHallOfFame(&mut self) -> Result<bool, String> {
+        self.checkHallOfFameEditable()?;
+        self.hallOfFame.clear();
+        self.markDirty(Region::HallOfFame);
 
-        // And return a success
         return Ok(true);
-    } 
+    }
 
-    /// Party Pokemon Setter for SPC EV
+    /// Hall of Fame Setter for Species
     /// 
-    /// Abstraction for pokemon::Pokemon::setEV_SPC()
-    pub fn setPartyPokemonEV_SPC(&mut self, partyPokemon: usize, newSPC: u16) -> Result<bool, String> {
-        // First we check that the party index is existing
-        if partyPokemon > self.party.len() - 1 {
-            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
-        }
+    /// This is an abstraction for hallOfFame::HallOfFame::setSpecies
+    pub fn setHallOfFameSpecies(&mut self, record: usize, slot: usize, index: i16) -> Result<bool, String> {
+        self.checkHallOfFameEditable()?;
+        self.hallOfFame.setSpecies(record, slot, index)?;
+        self.markDirty(Region::HallOfFame);
 
-        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
-        self.party[partyPokemon].setEV_SPC(newSPC);
+        return Ok(true);
+    }
+
+    /// Hall of Fame Setter for Level
+    /// 
+    /// This is an abstraction for hallOfFame::HallOfFame::setLevel
+    pub fn setHallOfFameLevel(&mut self, record: usize, slot: usize, newLevel: u8) -> Result<bool, String> {
+        self.checkHallOfFameEditable()?;
+        self.hallOfFame.setLevel(record, slot, newLevel)?;
+        self.markDirty(Region::HallOfFame);
 
-        // And return a success
         return Ok(true);
-    } 
+    }
 
-    /// Party Pokemon Setter for HP IV
+    /// Hall of Fame Setter for Nickname
     /// 
-    /// Abstraction for pokemon::Pokemon::setIV_HP()
-    pub fn setPartyPokemonIV_HP(&mut self, partyPokemon: usize, newHP: u16) -> Result<bool, String> {
-        // First we check that the party index is existing
-        if partyPokemon > self.party.len() - 1 {
+    /// This is an abstraction for hallOfFame::HallOfFame::setNickname
+    pub fn setHallOfFameNickname(&mut self, record: usize, slot: usize, newNickname: String) -> Result<bool, String> {
+        self.checkHallOfFameEditable()?;
+        self.checkPokemonName(&newNickname)?;
+        self.hallOfFame.setNickname(record, slot, newNickname)?;
+        self.markDirty(Region::HallOfFame);
+
+        return Ok(true);
+    }
+
+    /// Moves the player to a new map and coordinates
+    /// 
+    /// This is an abstraction for position::Position::teleport
+    pub fn teleport(&mut self, map: u8, x: u8, y: u8) -> Result<bool, String> {
+        self.position.teleport(map, x, y)?;
+        self.markDirty(Region::Position);
+
+        return Ok(true);
+    }
+
+    /// Setter for the last outdoor map, which warps that lead "outside" go to
+    pub fn setLastMap(&mut self, map: u8) -> Result<bool, String> {
+        self.position.setLastMap(map)?;
+        self.markDirty(Region::Position);
+
+        return Ok(true);
+    }
+
+    /// Setter for the map the player returns to after blacking out
+    pub fn setLastBlackoutMap(&mut self, map: u8) -> Result<bool, String> {
+        self.position.setLastBlackoutMap(map)?;
+        self.markDirty(Region::Position);
+
+        return Ok(true);
+    }
+
+    /// Sets or clears a named event flag or missable object
+    /// 
+    /// This is an abstraction for eventFlags::EventFlags::setByName
+    pub fn setFlag(&mut self, name: &str, value: bool) -> Result<bool, String> {
+        EventFlags::checkFlagVersion(name, &self.version)?;
+        self.eventFlags.setByName(name, value)?;
+        self.markDirty(Region::EventFlags);
+
+        return Ok(true);
+    }
+
+    /// Sets or clears an event flag or missable object by its index,
+    /// for flags that aren't named in the flag list
+    /// 
+    /// This is an abstraction for eventFlags::EventFlags::set
+    pub fn setFlagByIndex(&mut self, kind: FlagKind, index: usize, value: bool) -> Result<bool, String> {
+        self.eventFlags.set(kind, index, value)?;
+        self.markDirty(Region::EventFlags);
+
+        return Ok(true);
+    }
+
+    /// Setter for the friendship of the starter Pikachu, in Yellow
+    /// 
+    /// The starter Pikachu has to be in the party, as the friendship is only used for it.
+    pub fn setPikachuFriendship(&mut self, friendship: u8) -> Result<bool, String> {
+        self.getStarterPikachu()?;
+
+        self.pikachuFriendship = friendship;
+        self.markDirty(Region::PikachuFriendship);
+
+        return Ok(true);
+    }
+
+    /// Setter for the box that is open in the PC
+    pub fn setCurrentBox(&mut self, pcBox: usize) -> Result<bool, String> {
+
+        if pcBox >= self.layout.numBoxes {
+            return Err(formatError(format!("Box \"{}\" does not exist, there are only {} boxes", pcBox, self.layout.numBoxes)));
+        }
+
+        self.currentBox = pcBox;
+        self.markDirty(Region::PCBoxes);
+
+        return Ok(true);
+    }
+
+    /// Setter for the Pokemon in the daycare
+    ///
+    /// Setting the daycare to `None` takes the Pokemon out of the daycare, without returning it.
+    pub fn setDaycare(&mut self, pokemon: Option<Pokemon>) {
+        self.daycare = pokemon;
+        self.markDirty(Region::Daycare);
+    }
+
+    /// Moves a Pokemon from the party into the daycare
+    ///
+    /// The daycare must be empty, and the party can't be left without any Pokemon.
+    pub fn depositToDaycare(&mut self, partyPokemon: usize) -> Result<bool, String> {
+
+        if self.daycare.is_some() {
+            return Err(formatError(String::from("There is already a Pokemon in the daycare")));
+        } else if partyPokemon >= self.party.len() {
             return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        } else if self.party.len() == 1 {
+            return Err(formatError(String::from("The last Pokemon in the party can't be left at the daycare")));
         }
 
-        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
-        let changeHPResult = self.party[partyPokemon].setIV_HP(newHP);
+        self.daycare = Some(self.party.remove(partyPokemon));
+        self.markDirty(Region::Daycare);
+        self.markDirty(Region::Party);
 
-        // And return a success
-        return changeHPResult;
-    } 
+        return Ok(true);
+    }
 
-    /// Party Pokemon Setter for ATK IV
+    /// Moves the Pokemon in the daycare to the end of the party
+    pub fn withdrawFromDaycare(&mut self) -> Result<bool, String> {
+
+        if self.party.len() >= 6 {
+            return Err(formatError(String::from("The party is full")));
+        }
+
+        let pokemon = match self.daycare.take() {
+            Some(pokemon)   => pokemon,
+            None            => return Err(formatError(String::from("There is no Pokemon in the daycare")))
+        };
+
+        self.party.push(pokemon);
+        self.markDirty(Region::Daycare);
+        self.markDirty(Region::Party);
+
+        return Ok(true);
+    }
+
+    /// Returns the Pokemon in the daycare for editing, or an error if it is empty
+    fn getDaycarePokemon(&mut self) -> Result<&mut Pokemon, String> {
+        return match &mut self.daycare {
+            Some(pokemon)   => Ok(pokemon),
+            None            => Err(formatError(String::from("There is no Pokemon in the daycare")))
+        };
+    }
+
+    /// Daycare Pokemon Setter for Nickname
+    ///
+    /// This is an abstraction for pokemon::Pokemon::setNickname
+    pub fn setDaycarePokemonNick(&mut self, newNickname: String) -> Result<bool, String> {
+        self.checkPokemonName(&newNickname)?;
+        self.getDaycarePokemon()?.setNickname(newNickname)?;
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for Level
+    ///
+    /// This is an abstraction for pokemon::Pokemon::setLevel
+    pub fn setDaycarePokemonLevel(&mut self, newLevel: i8) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setLevel(newLevel)?;
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for EXP, which also changes its level
+    /// 
+    /// This is an abstraction for pokemon::Pokemon::setExp
+    pub fn setDaycarePokemonExp(&mut self, newExp: u32) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setExp(newExp)?;
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for Original Trainer ID
+    ///
+    /// This is an abstraction for pokemon::Pokemon::setOTID
+    pub fn setDaycarePokemonOTID(&mut self, newOTID: u16) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setOTID(newOTID);
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for Original Trainer Name
+    ///
+    /// This is an abstraction for pokemon::Pokemon::setOTN
+    pub fn setDaycarePokemonOTN(&mut self, newOTN: String) -> Result<bool, String> {
+        self.checkPokemonName(&newOTN)?;
+        self.getDaycarePokemon()?.setOTN(newOTN)?;
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for Status Condition
+    /// 
+    /// This is an abstraction for pokemon::Pokemon::setStatus
+    pub fn setDaycarePokemonStatus(&mut self, newStatus: StatusCondition) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setStatus(newStatus)?;
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for HP EV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setEV_HP()
+    pub fn setDaycarePokemonEV_HP(&mut self, newHP: u16) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setEV_HP(newHP);
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for ATK EV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setEV_ATK()
+    pub fn setDaycarePokemonEV_ATK(&mut self, newATK: u16) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setEV_ATK(newATK);
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for DEF EV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setEV_DEF()
+    pub fn setDaycarePokemonEV_DEF(&mut self, newDEF: u16) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setEV_DEF(newDEF);
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for SPD EV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setEV_SPD()
+    pub fn setDaycarePokemonEV_SPD(&mut self, newSPD: u16) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setEV_SPD(newSPD);
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for SPC EV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setEV_SPC()
+    pub fn setDaycarePokemonEV_SPC(&mut self, newSPC: u16) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setEV_SPC(newSPC);
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for HP IV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setIV_HP(), which always fails
+    /// as the HP IV is derived from the other four
+    pub fn setDaycarePokemonIV_HP(&mut self, newHP: u16) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setIV_HP(newHP)?;
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for ATK IV
     /// 
     /// Abstraction for pokemon::Pokemon::setIV_ATK()
-    pub fn setPartyPokemonIV_ATK(&mut self, partyPokemon: usize, newATK: u16) -> Result<bool, String> {
-        // First we check that the party index is existing
+    pub fn setDaycarePokemonIV_ATK(&mut self, newATK: u16) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setIV_ATK(newATK)?;
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for DEF IV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setIV_DEF()
+    pub fn setDaycarePokemonIV_DEF(&mut self, newDEF: u16) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setIV_DEF(newDEF)?;
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for SPD IV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setIV_SPD()
+    pub fn setDaycarePokemonIV_SPD(&mut self, newSPD: u16) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setIV_SPD(newSPD)?;
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Daycare Pokemon Setter for SPC IV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setIV_SPC()
+    pub fn setDaycarePokemonIV_SPC(&mut self, newSPC: u16) -> Result<bool, String> {
+        self.getDaycarePokemon()?.setIV_SPC(newSPC)?;
+        self.markDirty(Region::Daycare);
+
+        return Ok(true);
+    }
+
+    /// Party Pokemon Setter for Nickname.
+    /// 
+    /// This is an abstraction for pokemon::Pokemon::setNickname
+    pub fn setPartyPokemonNick(&mut self, partyPokemon: usize, newNickname: String) -> Result<bool, String> {
+        
+        // First we check that there is a Pokemon in the party at the index
         if partyPokemon > self.party.len() - 1 {
             return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
         }
 
-        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
-        let changeATKResult = self.party[partyPokemon].setIV_ATK(newATK);
+        // Names have to fit the character set of the saves region
+        self.checkPokemonName(&newNickname)?;
 
-        // And return a success
-        return changeATKResult;
-    } 
+        // Then we update the nickname, and store the result for Error Handling
+        let nicknameChangeRes = self.party[partyPokemon].setNickname(newNickname);
 
-    /// Party Pokemon Setter for DEF IV
+        // If the nickname change was unsuccessful, return the error
+        if nicknameChangeRes.is_err() {
+            return Err(nicknameChangeRes.unwrap_err());
+        } else {
+            self.markDirty(Region::Party);
+            return Ok(true);
+        }
+        
+    }
+
+    /// Party Pokemon Setter for Level
     /// 
-    /// Abstraction for pokemon::Pokemon::setIV_DEF()
-    pub fn setPartyPokemonIV_DEF(&mut self, partyPokemon: usize, newDEF: u16) -> Result<bool, String> {
+    /// This is an abstraction for pokemon::Pokemon::setLevel
+    pub fn setPartyPokemonLevel(&mut self, partyPokemon: usize, newLevel: i8) -> Result<bool, String> {
+        
         // First we check that the party index is existing
         if partyPokemon > self.party.len() - 1 {
             return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
         }
 
-        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
-        let changeDEFResult = self.party[partyPokemon].setIV_DEF(newDEF);
-
-        // And return a success
-        return changeDEFResult;
-    } 
+        // Then we update the pokemons level and store the result
+        let levelChangeResult = self.party[partyPokemon].setLevel(newLevel);
+    
+        if levelChangeResult.is_err() {
+            return Err(levelChangeResult.unwrap_err());
+        } else {
+            self.markDirty(Region::Party);
+            return Ok(true);
+        }
+    }
 
-    /// Party Pokemon Setter for SPD IV
+    /// Party Pokemon Setter for EXP, which also changes its level
     /// 
-    /// Abstraction for pokemon::Pokemon::setIV_SPD()
-    pub fn setPartyPokemonIV_SPD(&mut self, partyPokemon: usize, newSPD: u16) -> Result<bool, String> {
+    /// This is an abstraction for pokemon::Pokemon::setExp
+    pub fn setPartyPokemonExp(&mut self, partyPokemon: usize, newExp: u32) -> Result<bool, String> {
+
         // First we check that the party index is existing
         if partyPokemon > self.party.len() - 1 {
             return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
         }
 
-        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
-        let changeSPDResult = self.party[partyPokemon].setIV_SPD(newSPD);
+        self.party[partyPokemon].setExp(newExp)?;
+        self.markDirty(Region::Party);
 
-        // And return a success
-        return changeSPDResult;
-    } 
+        return Ok(true);
+    }
 
-    /// Party Pokemon Setter for SPC IV
+    /// Party Pokemon Setter for Status Condition
     /// 
-    /// Abstraction for pokemon::Pokemon::setIV_SPC()
-    pub fn setPartyPokemonIV_SPC(&mut self, partyPokemon: usize, newSPC: u16) -> Result<bool, String> {
+    /// This is an abstraction for pokemon::Pokemon::setStatus
+    pub fn setPartyPokemonStatus(&mut self, partyPokemon: usize, newStatus: StatusCondition) -> Result<bool, String> {
+
         // First we check that the party index is existing
         if partyPokemon > self.party.len() - 1 {
             return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
         }
 
-        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
-        let changeSPCResult = self.party[partyPokemon].setIV_SPC(newSPC);
+        self.party[partyPokemon].setStatus(newStatus)?;
+        self.markDirty(Region::Party);
 
-        // And return a success
-        return changeSPCResult;
-    } 
+        return Ok(true);
+    }
+
+    /// Party Pokemon Setter for OTID
+    /// 
+    /// This is an abstraction for pokemon::Pokemon::setOTID
+    /// 
+    /// **Note**: There is no range validation for the OTID in this function.
+    /// This is due to the fact that the typing (`u16`) provides the ranges for the value,
+    /// as [Pokemon Trainer ID's](https://bulbapedia.bulbagarden.net/wiki/Trainer_ID_number) in Gen 1
+    /// cannout be *under* 0 or *over* the 16-bit unsigned integer limit.
+    pub fn setPartyPokemonOTID(&mut self, partyPokemon: usize, newOTID: u16) -> Result<bool, String> {
+
+         // First we check that the party index is existing
+         if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Then we edit the OT ID
+        self.party[partyPokemon].setOTID(newOTID);
+        self.markDirty(Region::Party);
+
+        // And finally we return a successful result
+        return Ok(true);
+    }
+
+    /// Party Pokemon Setter for OT Nickname
+    /// 
+    /// This is an abstraction for pokemon::Pokemon::setOTN
+    pub fn setPartyPokemonOTN(&mut self, partyPokemon: usize, newOTN: String) -> Result<bool, String> {
+        
+        // First we check that the party index is existing
+        if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Names have to fit the character set of the saves region
+        self.checkPokemonName(&newOTN)?;
+
+        // Then we edit the OTN, and store the result
+        let changeOTNResult = self.party[partyPokemon].setOTN(newOTN);
+
+        // If we get an error, we return it
+        // Else, return a success
+        if changeOTNResult.is_err() {
+            return Err(changeOTNResult.unwrap_err());
+        } else {
+            self.markDirty(Region::Party);
+            return Ok(true);
+        }
+
+    }
+
+    /// Party Pokemon Setter for HP EV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setEV_HP()
+    pub fn setPartyPokemonEV_HP(&mut self, partyPokemon: usize, newHP: u16) -> Result<bool, String> {
+        // First we check that the party index is existing
+        if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
+        self.party[partyPokemon].setEV_HP(newHP);
+        self.markDirty(Region::Party);
+
+        // And return a success
+        return Ok(true);
+    } 
+
+    /// Party Pokemon Setter for ATK EV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setEV_ATK()
+    pub fn setPartyPokemonEV_ATK(&mut self, partyPokemon: usize, newATK: u16) -> Result<bool, String> {
+        // First we check that the party index is existing
+        if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
+        self.party[partyPokemon].setEV_ATK(newATK);
+        self.markDirty(Region::Party);
+
+        // And return a success
+        return Ok(true);
+    } 
+
+    /// Party Pokemon Setter for DEF EV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setEV_DEF()
+    pub fn setPartyPokemonEV_DEF(&mut self, partyPokemon: usize, newDEF: u16) -> Result<bool, String> {
+        // First we check that the party index is existing
+        if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
+        self.party[partyPokemon].setEV_DEF(newDEF);
+        self.markDirty(Region::Party);
+
+        // And return a success
+        return Ok(true);
+    } 
+
+    /// Party Pokemon Setter for SPD EV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setEV_SPD()
+    pub fn setPartyPokemonEV_SPD(&mut self, partyPokemon: usize, newSPD: u16) -> Result<bool, String> {
+        // First we check that the party index is existing
+        if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
+        self.party[partyPokemon].setEV_SPD(newSPD);
+        self.markDirty(Region::Party);
+
+        // And return a success
+        return Ok(true);
+    } 
+
+    /// Party Pokemon Setter for SPC EV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setEV_SPC()
+    pub fn setPartyPokemonEV_SPC(&mut self, partyPokemon: usize, newSPC: u16) -> Result<bool, String> {
+        // First we check that the party index is existing
+        if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
+        self.party[partyPokemon].setEV_SPC(newSPC);
+        self.markDirty(Region::Party);
+
+        // And return a success
+        return Ok(true);
+    } 
+
+    /// Party Pokemon Setter for HP IV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setIV_HP(), which always fails
+    /// as the HP IV is derived from the other four
+    pub fn setPartyPokemonIV_HP(&mut self, partyPokemon: usize, newHP: u16) -> Result<bool, String> {
+        // First we check that the party index is existing
+        if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
+        let changeHPResult = self.party[partyPokemon].setIV_HP(newHP);
+        if changeHPResult.is_ok() {
+            self.markDirty(Region::Party);
+        }
+
+        // And return a success
+        return changeHPResult;
+    } 
+
+    /// Party Pokemon Setter for ATK IV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setIV_ATK()
+    pub fn setPartyPokemonIV_ATK(&mut self, partyPokemon: usize, newATK: u16) -> Result<bool, String> {
+        // First we check that the party index is existing
+        if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
+        let changeATKResult = self.party[partyPokemon].setIV_ATK(newATK);
+        if changeATKResult.is_ok() {
+            self.markDirty(Region::Party);
+        }
+
+        // And return a success
+        return changeATKResult;
+    } 
+
+    /// Party Pokemon Setter for DEF IV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setIV_DEF()
+    pub fn setPartyPokemonIV_DEF(&mut self, partyPokemon: usize, newDEF: u16) -> Result<bool, String> {
+        // First we check that the party index is existing
+        if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
+        let changeDEFResult = self.party[partyPokemon].setIV_DEF(newDEF);
+        if changeDEFResult.is_ok() {
+            self.markDirty(Region::Party);
+        }
+
+        // And return a success
+        return changeDEFResult;
+    } 
+
+    /// Party Pokemon Setter for SPD IV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setIV_SPD()
+    pub fn setPartyPokemonIV_SPD(&mut self, partyPokemon: usize, newSPD: u16) -> Result<bool, String> {
+        // First we check that the party index is existing
+        if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
+        let changeSPDResult = self.party[partyPokemon].setIV_SPD(newSPD);
+        if changeSPDResult.is_ok() {
+            self.markDirty(Region::Party);
+        }
+
+        // And return a success
+        return changeSPDResult;
+    } 
+
+    /// Party Pokemon Setter for SPC IV
+    /// 
+    /// Abstraction for pokemon::Pokemon::setIV_SPC()
+    pub fn setPartyPokemonIV_SPC(&mut self, partyPokemon: usize, newSPC: u16) -> Result<bool, String> {
+        // First we check that the party index is existing
+        if partyPokemon > self.party.len() - 1 {
+            return Err(formatError(format!("There is no Pokemon in party slot {}", partyPokemon)));
+        }
+
+        // Then we perform the change (no need to store the result, as EVs don't return Result objects)
+        let changeSPCResult = self.party[partyPokemon].setIV_SPC(newSPC);
+        if changeSPCResult.is_ok() {
+            self.markDirty(Region::Party);
+        }
+
+        // And return a success
+        return changeSPCResult;
+    } 
+
+
+    // ========   TEXT ENCODING    ======== 
+
+    /// Decodes a name with the character set of the saves region
+    fn decodeName(layout: &SaveLayout, encoded: &[i16;11]) -> String {
+        return match layout.region {
+            GameRegion::International   => textDecode(encoded),
+            GameRegion::Japanese        => kanaDecode(encoded)
+        };
+    }
+
+    /// Encodes a name with the character set of the saves region
+    fn encodeName(layout: &SaveLayout, decoded: &String) -> Result<[i16;11], String> {
+        return match layout.region {
+            GameRegion::International   => textEncode(decoded),
+            GameRegion::Japanese        => kanaEncode(decoded)
+        };
+    }
+
+
+    /// Checks that a Pokemon or Original Trainer name can be written in the saves region
+    /// 
+    /// Names that are too long for an international save are left for the Pokemon setters to report.
+    fn checkPokemonName(&self, name: &String) -> Result<bool, String> {
+        if self.layout.region == GameRegion::International && name.chars().count() > 10 {
+            return Ok(true);
+        }
+
+        Self::encodeName(self.layout, name)?;
+
+        return Ok(true);
+    }
 
 
     // ========   SAVE FILE RETRIEVAL    ======== 
 
+    /// Reads an encoded name from the save file
+    /// 
+    /// Names are 11 bytes, or 6 bytes in Japanese saves, where the rest is filled with the terminator.
+    fn getEncodedNameFromSave(save: &Vec<u8>, layout: &SaveLayout, currAddr: &usize) -> [i16;11] {
+        let mut name: [i16; 11] = [TEXT_TERMINATOR; 11];
+        for num in 0..layout.nameLength {
+            name[num] = save[currAddr+num] as i16;
+        }
+        return name;
+    }
+
     /// Retrieves the name from the save file
     /// 
     /// Since most Pokemon games use character encoding, we have to decode it.
     /// 
     /// [Gen 1](https://bulbapedia.bulbagarden.net/wiki/Character_encoding_(Generation_I))
-    fn getNameFromSave(save: &Vec<u8>) -> [i16;11] {
-        let mut name: [i16; 11] = [0; 11];
-        for num in 0..11 {
-            name[num] = format!("{}",save[NAME_ADDR+num]).parse::<i16>().unwrap();
-        }
-        return name;
+    fn getNameFromSave(save: &Vec<u8>, layout: &SaveLayout) -> [i16;11] {
+        return Self::getEncodedNameFromSave(save, layout, &layout.nameAddr);
+    }
+
+    /// Retrieves the rivals name from the save file
+    /// 
+    /// This uses the same character encoding as the players name.
+    fn getRivalNameFromSave(save: &Vec<u8>, layout: &SaveLayout) -> [i16;11] {
+        return Self::getEncodedNameFromSave(save, layout, &layout.rivalAddr);
     }
 
     /// Retrieves the amount of money the player has
-    /// TODO: Figure out how to do this without using strings
-    fn getMoneyFromSave(save: &Vec<u8>) -> u32{
-        
-        // let mut moneyInt:u32 = 0b0;
-        // for moneyOffset in 0..3 {
-        //     moneyInt |= save[MONEY_ADDR+moneyOffset] as u32;
-        //     moneyInt <<= 8;
-        // }
+    /// 
+    /// Money is stored as 3 bytes of [Binary Coded Decimal](https://en.wikipedia.org/wiki/Binary-coded_decimal)
+    fn getMoneyFromSave(save: &Vec<u8>, layout: &SaveLayout) -> Result<u32, bcd::BCDError> {
+        return bcd::decode(&save[layout.moneyAddr..layout.moneyAddr+3]);
+    }
+
+    /// Retrieves the amount of Game Corner coins the player has
+    /// 
+    /// Coins are stored as 2 bytes of Binary Coded Decimal, the same as money
+    fn getCoinsFromSave(save: &Vec<u8>, layout: &SaveLayout) -> Result<u16, bcd::BCDError> {
+        let coins = bcd::decode(&save[layout.coinsAddr..layout.coinsAddr+2])?;
+        return Ok(coins as u16);
+    }
+
+    /// Retrieves the trainer ID
+    fn getTrainerIDFromSave(save: &Vec<u8>, layout: &SaveLayout) -> u16 {
+
+        let mut trainerID:u16 = 0b0;
+
+        trainerID |= (save[layout.idAddr] as u16) << 8;
+        trainerID |= save[layout.idAddr+1] as u16;
+
+        return trainerID;
+    }
+
+    /// Retrieves an item list, such as the Bag or the PC items
+    /// 
+    /// Item lists start with the amount of items, followed by an
+    /// (index, quantity) pair for each item, and end with 0xFF.
+    fn getItemsFromSave(save: &Vec<u8>, listAddr: &usize, capacity: usize) -> Vec<Item> {
+        let mut items: Vec<Item> = Vec::new();
+        // A corrupted count shouldn't make us read past the end of the list
+        let itemCount = (save[*listAddr] as usize).min(capacity);
+
+        for item in 0..itemCount {
+            let itemAddr = listAddr + 1 + (item * 2);
+            let index = save[itemAddr];
+            let quantity = save[itemAddr+1];
+
+            // Glitch items aren't in the item list, but should still be kept
+            items.push(Item::get(index, quantity).unwrap_or(Item::unknown(index, quantity)));
+        }
+
+        return items;
+    }
+
+    /// Retrieves the owned and seen flags of the Pokedex
+    fn getPokedexFromSave(save: &Vec<u8>, layout: &SaveLayout) -> Pokedex {
+        let mut owned: [u8; POKEDEX_BYTES] = [0; POKEDEX_BYTES];
+        let mut seen: [u8; POKEDEX_BYTES] = [0; POKEDEX_BYTES];
+
+        owned.copy_from_slice(&save[layout.pokedexOwnedAddr..layout.pokedexOwnedAddr+POKEDEX_BYTES]);
+        seen.copy_from_slice(&save[layout.pokedexSeenAddr..layout.pokedexSeenAddr+POKEDEX_BYTES]);
+
+        return Pokedex::get(owned, seen);
+    }
+
+    /// Retrieves the time the player has spent in game
+    fn getPlayTimeFromSave(save: &Vec<u8>, layout: &SaveLayout) -> PlayTime {
+        return PlayTime::get(   save[layout.playTimeAddr],
+                                save[layout.playTimeAddr+1],
+                                save[layout.playTimeAddr+2],
+                                save[layout.playTimeAddr+3],
+                                save[layout.playTimeAddr+4]
+                            );
+    }
+
+    /// Retrieves the teams that have entered the Hall of Fame
+    /// 
+    /// Only the last 50 teams are kept, even though the count goes higher.
+    /// Teams of less than 6 Pokemon end with a species of 0xFF.
+    fn getHallOfFameFromSave(save: &Vec<u8>, layout: &SaveLayout) -> HallOfFame {
+        let count = save[layout.hofCountAddr];
+        let mut records: Vec<Vec<HallOfFameEntry>> = Vec::new();
+
+        for record in 0..(count as usize).min(HOF_MAX_RECORDS) {
+            let mut team: Vec<HallOfFameEntry> = Vec::new();
+
+            for slot in 0..6 {
+                let entryAddr = layout.hofEntryAddr(record, slot);
+
+                let index = save[entryAddr] as i16;
+                if index == 0xFF {
+                    break;
+                }
+
+                let level = save[entryAddr+1];
+                let nickname = Self::getPokemonNickFromSave(save, layout, &(entryAddr+2));
+
+                // Glitch Pokemon aren't in the species list, but should still be kept
+                let mut entry = HallOfFameEntry::get(index, level, nickname.clone()).unwrap_or(HallOfFameEntry::unknown(index, level, nickname));
+                entry.keepEncodedNickname(Self::getEncodedNameFromSave(save, layout, &(entryAddr+2)));
+                team.push(entry);
+            }
+
+            records.push(team);
+        }
+
+        return HallOfFame::get(records, count);
+    }
+
+    /// Retrieves the players position, and the maps they will return to
+    /// 
+    /// Maps that aren't in the map list are loaded as unknown, instead of failing the load
+    fn getPositionFromSave(save: &Vec<u8>, layout: &SaveLayout) -> Position {
+        let map = Map::get(save[layout.curMapAddr]).unwrap_or(Map::unknown(save[layout.curMapAddr]));
+        let lastMap = Map::get(save[layout.lastMapAddr]).unwrap_or(Map::unknown(save[layout.lastMapAddr]));
+        let lastBlackoutMap = Map::get(save[layout.lastBlackoutAddr]).unwrap_or(Map::unknown(save[layout.lastBlackoutAddr]));
+        let viewPointer = u16::from_le_bytes([save[layout.viewPointerAddr], save[layout.viewPointerAddr+1]]);
+
+        return Position::get(   map,
+                                save[layout.xCoordAddr],
+                                save[layout.yCoordAddr],
+                                save[layout.xBlockAddr],
+                                save[layout.yBlockAddr],
+                                viewPointer,
+                                lastMap,
+                                lastBlackoutMap
+                            );
+    }
+
+    /// Retrieves the event flags and missable objects
+    fn getEventFlagsFromSave(save: &Vec<u8>, layout: &SaveLayout) -> EventFlags {
+        let mut events: [u8; EVENT_FLAG_BYTES] = [0; EVENT_FLAG_BYTES];
+        let mut missables: [u8; MISSABLE_BYTES] = [0; MISSABLE_BYTES];
+
+        events.copy_from_slice(&save[layout.eventFlagsAddr..layout.eventFlagsAddr+EVENT_FLAG_BYTES]);
+        missables.copy_from_slice(&save[layout.missablesAddr..layout.missablesAddr+MISSABLE_BYTES]);
+
+        return EventFlags::get(events, missables);
+    }
+
+    /// Retrieves the players party of Pokemon
+    fn getPartyFromSave(save: &Vec<u8>, layout: &SaveLayout) -> Vec<Pokemon> {
+        let mut party:  Vec<Pokemon> = Vec::new();
+        let partyAddr = layout.partyAddr;
+
+        for creature in 0..save[partyAddr] as usize {
+            let pkmnAddress: usize = partyAddr + 0x8 + (creature * 0x2C);
+            let nickAddress: usize = partyAddr + layout.partyNickOff + (creature * layout.nameLength);
+
+            // Get current HP
+            let hp = Self::getPokemonHPFromSave(&save,&pkmnAddress);
+            // Nickname Obtaining code
+            let nickname = Self::getPokemonNickFromSave(&save, layout, &nickAddress);
+            // Moves Obtaining code
+            let moves = Self::getPokemonMovesFromSave(&save,&pkmnAddress);
+            // EV Obtaining code
+            let evs: [u16;5] = Self::getPokemonEVsFromSave(&save,&pkmnAddress);
+            // Stat Obtaining Code
+            let stats: [u16;5] = Self::getPokemonStatsFromSave(&save,&pkmnAddress);
+            // IV Obtaining Code
+            let ivs: [u16;5] = Self::getPokemonIVsFromSave(&save,&pkmnAddress);
+            // Original Trainer Obtaining Code
+            let ot = Self::getPokemonOTIDFromSave(&save,&pkmnAddress);
+            let otnAddress: usize = partyAddr + layout.partyOTNOff + (creature * layout.nameLength);
+            let otn = Self::getPokemonOTNameFromSave(&save, layout, &otnAddress);
+
+            let mut pokemon = Pokemon::get(    save[pkmnAddress] as i16,
+                                        save[pkmnAddress+0x21] as i8,
+                                        Self::getPokemonExpFromSave(&save, &pkmnAddress),
+                                        nickname,
+                                        moves,
+                                        ot,
+                                        otn,
+                                        hp, 
+                                        save[pkmnAddress+STATUS_OFF],
+                                        Self::getPokemonTypesFromSave(&save, &pkmnAddress),
+                                        save[pkmnAddress+CATCH_RATE_OFF],
+                                        evs, ivs, stats);
+            pokemon.keepEncodedNames(Self::getEncodedNameFromSave(save, layout, &nickAddress), Self::getEncodedNameFromSave(save, layout, &otnAddress));
+
+            party.push(pokemon);
+
+            // println!("Current Pokemon: {:#?}", party[creature]);
+        }
+
+        return party;
+    }
+
+    /// Retrieves all of the players PC boxes
+    ///
+    /// The copy of the current box in banks 2 and 3 can be outdated,
+    /// so the working copy in bank 1 is used for it instead.
+    fn getPCBoxesFromSave(save: &Vec<u8>, layout: &SaveLayout) -> Vec<Vec<Pokemon>>{
+        let mut boxes: Vec<Vec<Pokemon>> = Vec::new();
+
+        // Until the player first switches box, the banks hold whatever was in SRAM and only the working copy is real
+        let initialised = save[layout.currentBoxAddr] & 0x80 != 0;
+
+        for pcBox in 0..layout.numBoxes {
+            if !initialised {
+                boxes.push(Vec::new());
+                continue;
+            }
+
+            // The boxes first two bytes
+            let currAddr = layout.boxAddr(pcBox);
+            // println!("Pokemon in box {} at {:X}: {}",pcBox+1,currAddr,save[currAddr]);
+
+            boxes.push(Self::getBoxFromSave(save, layout, &currAddr));
+        }
+
+        let currentBox = Self::getCurrentBoxFromSave(save, layout);
+        boxes[currentBox] = Self::getBoxFromSave(save, layout, &layout.currentBoxDataAddr);
+
+        // println!("{:#?}",boxes);
+        return boxes;
+    }
+
+    /// Retrieves the index of the box that is open in the PC
+    fn getCurrentBoxFromSave(save: &Vec<u8>, layout: &SaveLayout) -> usize {
+        return (save[layout.currentBoxAddr] & 0x7F) as usize % layout.numBoxes;
+    }
+
+    /// Retrieves every Pokemon in a single PC box
+    fn getBoxFromSave(save: &Vec<u8>, layout: &SaveLayout, currAddr: &usize) -> Vec<Pokemon> {
+        let mut currBox: Vec<Pokemon> = Vec::new();
+        let pkmnInBox = (save[*currAddr] as usize).min(layout.boxCapacity);
+
+        for creature in 0..pkmnInBox {
+            let pkmnAddress = currAddr + layout.pcPkmnOff + (0x21 * creature);
+            let nickAddress = currAddr + layout.pcNickOff + (creature*layout.nameLength);
+            let otnAddress = currAddr + layout.pcTrainerOff + (creature*layout.nameLength);
+            // println!("Original Trainer name at: {:X}",otnAddress);
+
+            currBox.push(Self::getBoxPokemonFromSave(save, layout, &pkmnAddress, &nickAddress, &otnAddress));
+        }
+
+        return currBox;
+    }
+
+    /// Retrieves the Pokemon in the daycare, if there is one
+    fn getDaycareFromSave(save: &Vec<u8>, layout: &SaveLayout) -> Option<Pokemon> {
+        if save[layout.daycareInUseAddr] == 0 {
+            return None;
+        }
+
+        return Some(Self::getBoxPokemonFromSave(save, layout, &layout.daycarePkmnAddr, &layout.daycareNickAddr, &layout.daycareOTNAddr));
+    }
+
+    /// Retrieves a Pokemon stored in the box format, used by the PC and the daycare.
+    ///
+    /// The nickname and Original Trainer name are stored apart from the Pokemon data.
+    fn getBoxPokemonFromSave(save: &Vec<u8>, layout: &SaveLayout, pkmnAddress: &usize, nickAddress: &usize, otnAddress: &usize) -> Pokemon {
+        let currSpecies: i16 = save[*pkmnAddress] as i16;
+        let hp = Self::getPokemonHPFromSave(&save, pkmnAddress);
+        let ot = Self::getPokemonOTIDFromSave(&save, pkmnAddress);
+        let moves = Self::getPokemonMovesFromSave(&save, pkmnAddress);
+        let nickname = Self::getPokemonNickFromSave(&save, layout, nickAddress);
+        let evs: [u16;5] = Self::getPokemonEVsFromSave(&save, pkmnAddress);
+        let ivs: [u16;5] = Self::getPokemonIVsFromSave(&save, pkmnAddress);
+        let level: i8 = save[pkmnAddress+0x03] as i8;
+        let otn = Self::getPokemonOTNameFromSave(&save, layout, otnAddress);
+
+        // Boxed Pokemon don't have their stats stored, so they are calculated once the Pokemon is made
+        // https://bulbapedia.bulbagarden.net/wiki/Box_trick
+        let stats: [u16;5] = [0,0,0,0,0];
+
+        let mut pokemon = Pokemon::get(currSpecies,
+                            level,
+                            Self::getPokemonExpFromSave(&save, pkmnAddress),
+                            nickname,
+                            moves,
+                            ot,
+                            otn,
+                            hp,
+                            save[pkmnAddress+STATUS_OFF],
+                            Self::getPokemonTypesFromSave(&save, pkmnAddress),
+                            save[pkmnAddress+CATCH_RATE_OFF],
+                            evs,
+                            ivs,
+                            stats
+                        );
+        pokemon.recalculateStats();
+        pokemon.keepEncodedNames(Self::getEncodedNameFromSave(save, layout, nickAddress), Self::getEncodedNameFromSave(save, layout, otnAddress));
+
+        return pokemon;
+    }
+
+    /// Function for retrieving a Pokemons Original Trainers ID
+    fn getPokemonOTIDFromSave(save: &Vec<u8>, currAddr: &usize) -> u16{
+        let mut pokemonOTID:u16 = 0b0;
+
+        pokemonOTID |= (save[currAddr+OT_OFF] as u16) << 8;
+        pokemonOTID |= save[currAddr+OT_OFF+1] as u16;
+
+        return pokemonOTID;
+    }
+
+    /// Function for retrieving a Pokemons Original Trainers Name
+    fn getPokemonOTNameFromSave(save: &Vec<u8>, layout: &SaveLayout, currAddr: &usize) -> String {
+        let encodedName = Self::getEncodedNameFromSave(save, layout, currAddr);
+
+        return Self::decodeName(layout, &encodedName);
+    }
+
+    /// Function for retrieving a Pokemons total experience, which is 3 bytes long
+    fn getPokemonExpFromSave(save: &Vec<u8>, currAddr: &usize) -> u32 {
+        return u32::from_be_bytes([0, save[currAddr+EXP_OFF], save[currAddr+EXP_OFF+1], save[currAddr+EXP_OFF+2]]);
+    }
+
+    /// Function for retrieving the two type IDs stored in a Pokemon
+    fn getPokemonTypesFromSave(save: &Vec<u8>, currAddr: &usize) -> [u8;2] {
+        return [save[currAddr+TYPE_OFF], save[currAddr+TYPE_OFF+1]];
+    }
+
+    /// Function for retrieving the Pokemons current Health Points
+    fn getPokemonHPFromSave(save: &Vec<u8>, currAddr: &usize) -> i16{
+
+        let mut pokemonHP:i16 = 0x0;
+
+        pokemonHP |=  (save[currAddr+HP_OFF] as i16) << 8;
+        pokemonHP |=  save[currAddr+HP_OFF + 1] as i16;
+
+        return  pokemonHP;
+    }
+
+    /// Function for retrieving a Pokemons Nickname.
+    /// 
+    /// **Note**: This function will automatically decode it into a String
+    fn getPokemonNickFromSave(save: &Vec<u8>, layout: &SaveLayout, currAddr: &usize) -> String {
+        let encodedNick = Self::getEncodedNameFromSave(save, layout, currAddr);
+
+        return Self::decodeName(layout, &encodedNick);
+    }
+
+    /// Function for retrieving a Pokemons base stats
+    fn getPokemonStatsFromSave(save: &Vec<u8>,currAddr: &usize) -> [u16;5] {
+        let mut stats: [u16;5] = [0x0; 5];
+        
+        for stat in 0..5 {
+            let currAddr = currAddr+STAT_OFF+(stat*2);
+            stats[stat] |= (save[currAddr] as u16) << 8;
+            stats[stat] |= save[currAddr + 1] as u16;
+        }
+        
+        return stats;
+    }
+
+    /// Function for retrieving a Pokemons Effort Values
+    fn getPokemonEVsFromSave(save: &Vec<u8>,currAddr: &usize) -> [u16;5] {
+        let mut evs: [u16;5] = [0x0; 5];
+
+        for stat in 0..5 {
+            let currAddr = currAddr+EV_OFF+(stat*2);
+            evs[stat] |= (save[currAddr] as u16) << 8;
+            evs[stat] |= save[currAddr + 1] as u16;
+        }
+        
+        return evs;
+    }
+
+    /// Function for retrieving data about Pokemons moves.
+    fn getPokemonMovesFromSave(save: &Vec<u8>, currAddr: &usize) -> Vec<Move>{
+    let mut returnVec: Vec<Move> = Vec::new();
+    let moveAddr = currAddr + MOVE_OFF;
+
+    for moves in 0..4 {
+        let moveIndex = save[moveAddr+moves] as u16;
+
+        // As per the Wikiw (https://m.bulbapedia.bulbagarden.net/wiki/Pok%C3%A9mon_data_structure_(Generation_I)#PP), PP values are determined by:
+        // The current PP-Up being the first two bits of the move
+        // The current PP value being the remaining 6 bits
+        let ppValues = save[currAddr+PP_OFF+moves];
+
+        let PP: u16 = ((ppValues << 2) >> 2) as u16 ;
+        let PPUp: u8 = ppValues >> 6;
+
+        if moveIndex == 0 {
+            returnVec.push(Move::empty());
+        } else {
+            returnVec.push(Move::get(moveIndex, PP, PPUp).unwrap());
+        }
+    }
+
+    return returnVec;
+}
+
+    /// Function for retrieving a Pokemons Individual Values 
+    /// Also known as [Determinant Values](https://m.bulbapedia.bulbagarden.net/wiki/Individual_values)
+    /// 
+    /// **NOTE**: This function does not have a bit manip test as the original implementation
+    /// was faulty. 
+    fn getPokemonIVsFromSave(save: &Vec<u8>,currAddr: &usize) -> [u16;5]{
+
+        let atk = (save[currAddr+IV_OFF] >> 4) as u16;
+        let def = ((save[currAddr+IV_OFF] << 4) >> 4) as u16;
+        let spd = (save[currAddr+IV_OFF+1] >> 4) as u16;
+        let spc = ((save[currAddr+IV_OFF+1] << 4) >> 4) as u16;
+
+       
+        // For the HPIVs we have to get the last bits of all four stats
+        let mut hp = 0;
+        hp |= (atk & 1) << 3 | (def & 1) << 2 | (spd & 1) << 1 | (spc & 1);
+
+        let ivs: [u16;5] = [
+                                hp,
+                                atk,
+                                def,
+                                spd,
+                                spc
+                            ];
+        
+        return ivs;
+    }
+
+
+    // ========   SAVE FILE WRITING    ======== 
+
+    /// Encodes a name and writes it to the save file
+    /// 
+    /// Names are padded out to the full 11 bytes (6 in Japanese saves) with the terminator, so that the old name is overwritten.
+    /// Names that haven't been edited are written back as they were read, so characters that can't be decoded are kept.
+    fn writeNameToSave(save: &mut Vec<u8>, layout: &SaveLayout, currAddr: &usize, name: &String, encoded: &Option<[i16;11]>) -> Result<bool, String> {
+        let encodedName = match encoded {
+            Some(encodedName)   => *encodedName,
+            None                => Self::encodeName(layout, name)?
+        };
+
+        for char in 0..layout.nameLength {
+            save[currAddr+char] = encodedName[char] as u8;
+        }
+
+        return Ok(true);
+    }
+
+    /// Writes the amount of money the player has
+    /// 
+    /// Money is stored as 3 bytes of [Binary Coded Decimal](https://en.wikipedia.org/wiki/Binary-coded_decimal)
+    fn writeMoneyToSave(save: &mut Vec<u8>, layout: &SaveLayout, money: &u32) {
+        // setMoney has already checked that the amount fits into 3 bytes
+        let encodedMoney = bcd::encode(*money, 3).unwrap();
+
+        save[layout.moneyAddr..layout.moneyAddr+3].copy_from_slice(&encodedMoney);
+    }
+
+    /// Writes the amount of Game Corner coins the player has
+    fn writeCoinsToSave(save: &mut Vec<u8>, layout: &SaveLayout, coins: &u16) {
+        // setCoins has already checked that the amount fits into 2 bytes
+        let encodedCoins = bcd::encode(*coins as u32, 2).unwrap();
+
+        save[layout.coinsAddr..layout.coinsAddr+2].copy_from_slice(&encodedCoins);
+    }
+
+    /// Writes the trainer ID
+    fn writeTrainerIDToSave(save: &mut Vec<u8>, layout: &SaveLayout, id: &u16) {
+        save[layout.idAddr]   = (id >> 8) as u8;
+        save[layout.idAddr+1] = *id as u8;
+    }
+
+    /// Writes an item list, such as the Bag or the PC items
+    fn writeItemsToSave(save: &mut Vec<u8>, listAddr: &usize, inventory: &Inventory) {
+        let items = inventory.getItems();
+
+        save[*listAddr] = items.len() as u8;
+
+        for (item, currItem) in items.iter().enumerate() {
+            let itemAddr = listAddr + 1 + (item * 2);
+            save[itemAddr]   = *currItem.getIndex();
+            save[itemAddr+1] = *currItem.getQuantity();
+        }
+
+        save[listAddr + 1 + (items.len() * 2)] = 0xFF;
+    }
+
+    /// Writes the owned and seen flags of the Pokedex
+    fn writePokedexToSave(save: &mut Vec<u8>, layout: &SaveLayout, pokedex: &Pokedex) {
+        save[layout.pokedexOwnedAddr..layout.pokedexOwnedAddr+POKEDEX_BYTES].copy_from_slice(pokedex.getOwnedFlags());
+        save[layout.pokedexSeenAddr..layout.pokedexSeenAddr+POKEDEX_BYTES].copy_from_slice(pokedex.getSeenFlags());
+    }
+
+    /// Writes the time the player has spent in game
+    fn writePlayTimeToSave(save: &mut Vec<u8>, layout: &SaveLayout, playTime: &PlayTime) {
+        save[layout.playTimeAddr]   = *playTime.getHours();
+        save[layout.playTimeAddr+1] = *playTime.getMaxedFlag();
+        save[layout.playTimeAddr+2] = *playTime.getMinutes();
+        save[layout.playTimeAddr+3] = *playTime.getSeconds();
+        save[layout.playTimeAddr+4] = *playTime.getFrames();
+    }
+
+    /// Writes the teams that have entered the Hall of Fame
+    fn writeHallOfFameToSave(save: &mut Vec<u8>, layout: &SaveLayout, hallOfFame: &HallOfFame) -> Result<bool, String> {
+        save[layout.hofCountAddr] = *hallOfFame.getCount();
+
+        for (record, team) in hallOfFame.getRecords().iter().enumerate() {
+            for (slot, entry) in team.iter().enumerate() {
+                let entryAddr = layout.hofEntryAddr(record, slot);
+
+                save[entryAddr]   = *entry.getSpecies().getIndex() as u8;
+                save[entryAddr+1] = *entry.getLevel();
+                Self::writeNameToSave(save, layout, &(entryAddr+2), entry.getNickname(), entry.getEncodedNickname())?;
+            }
+
+            // Teams of less than 6 are terminated
+            if team.len() < 6 {
+                save[layout.hofEntryAddr(record, team.len())] = 0xFF;
+            }
+        }
+
+        return Ok(true);
+    }
+
+    /// Writes the players position, and the maps they will return to
+    fn writePositionToSave(save: &mut Vec<u8>, layout: &SaveLayout, position: &Position) {
+        let viewPointer = position.getViewPointer().to_le_bytes();
+
+        save[layout.curMapAddr] = *position.getMap().getIndex();
+        save[layout.viewPointerAddr] = viewPointer[0];
+        save[layout.viewPointerAddr+1] = viewPointer[1];
+        save[layout.yCoordAddr] = *position.getY();
+        save[layout.xCoordAddr] = *position.getX();
+        save[layout.yBlockAddr] = *position.getYBlock();
+        save[layout.xBlockAddr] = *position.getXBlock();
+        save[layout.lastMapAddr] = *position.getLastMap().getIndex();
+        save[layout.lastBlackoutAddr] = *position.getLastBlackoutMap().getIndex();
+    }
+
+    /// Writes the event flags and missable objects
+    fn writeEventFlagsToSave(save: &mut Vec<u8>, layout: &SaveLayout, eventFlags: &EventFlags) {
+        save[layout.eventFlagsAddr..layout.eventFlagsAddr+EVENT_FLAG_BYTES].copy_from_slice(eventFlags.getEventFlags());
+        save[layout.missablesAddr..layout.missablesAddr+MISSABLE_BYTES].copy_from_slice(eventFlags.getMissableFlags());
+    }
+
+    /// Writes the players party of Pokemon
+    fn writePartyToSave(save: &mut Vec<u8>, layout: &SaveLayout, party: &Vec<Pokemon>) -> Result<bool, String> {
+        let partyAddr = layout.partyAddr;
+
+        save[partyAddr] = party.len() as u8;
+
+        for (creature, pokemon) in party.iter().enumerate() {
+            let pkmnAddress: usize = partyAddr + 0x8 + (creature * 0x2C);
+            let nickAddress: usize = partyAddr + layout.partyNickOff + (creature * layout.nameLength);
+
+            // The species list that comes before the Pokemon data
+            save[partyAddr + 1 + creature] = *pokemon.getSpecies().getIndex() as u8;
+
+            Self::writePokemonToSave(save, &pkmnAddress, pokemon);
+            Self::writePokemonStatsToSave(save, &pkmnAddress, pokemon);
+            save[pkmnAddress+0x21] = *pokemon.getLevel() as u8;
+
+            Self::writeNameToSave(save, layout, &nickAddress, pokemon.getNickname(), pokemon.getEncodedNickname())?;
+            Self::writeNameToSave(save, layout, &(partyAddr + layout.partyOTNOff + (creature * layout.nameLength)), pokemon.getOTN(), pokemon.getEncodedOTN())?;
+        }
+
+        // The species list is terminated by 0xFF
+        save[partyAddr + 1 + party.len()] = 0xFF;
+
+        return Ok(true);
+    }
+
+    /// Writes all of the players PC boxes
+    ///
+    /// The current box is written to both the working copy and its bank, so that they stay in sync.
+    fn writePCBoxesToSave(save: &mut Vec<u8>, layout: &SaveLayout, boxes: &Vec<Vec<Pokemon>>, currentBox: &usize) -> Result<bool, String> {
+
+        for (pcBox, currBox) in boxes.iter().enumerate() {
+            let currAddr = layout.boxAddr(pcBox);
+            Self::writeBoxToSave(save, layout, &currAddr, currBox)?;
+        }
+
+        Self::writeBoxToSave(save, layout, &layout.currentBoxDataAddr, &boxes[*currentBox])?;
+
+        // Every bank has just been written, so the boxes are marked as initialised,
+        // otherwise the game would wipe them the first time the player switches box
+        save[layout.currentBoxAddr] = 0x80 | *currentBox as u8;
+
+        return Ok(true);
+    }
+
+    /// Writes every Pokemon in a single PC box
+    fn writeBoxToSave(save: &mut Vec<u8>, layout: &SaveLayout, currAddr: &usize, currBox: &Vec<Pokemon>) -> Result<bool, String> {
+
+        save[*currAddr] = currBox.len() as u8;
+
+        for (creature, pokemon) in currBox.iter().enumerate() {
+            let pkmnAddress = currAddr + layout.pcPkmnOff + (0x21 * creature);
+            let nickAddress = currAddr + layout.pcNickOff + (creature*layout.nameLength);
+
+            save[currAddr + 1 + creature] = *pokemon.getSpecies().getIndex() as u8;
+
+            Self::writePokemonToSave(save, &pkmnAddress, pokemon);
+
+            Self::writeNameToSave(save, layout, &nickAddress, pokemon.getNickname(), pokemon.getEncodedNickname())?;
+            Self::writeNameToSave(save, layout, &(currAddr+layout.pcTrainerOff+(creature*layout.nameLength)), pokemon.getOTN(), pokemon.getEncodedOTN())?;
+        }
+
+        save[currAddr + 1 + currBox.len()] = 0xFF;
+
+        return Ok(true);
+    }
+
+    /// Writes the Pokemon in the daycare.
+    ///
+    /// When the daycare is empty only the "in use" flag is cleared, as the game does.
+    fn writeDaycareToSave(save: &mut Vec<u8>, layout: &SaveLayout, daycare: &Option<Pokemon>) -> Result<bool, String> {
+        match daycare {
+            Some(pokemon) => {
+                save[layout.daycareInUseAddr] = 1;
+                Self::writePokemonToSave(save, &layout.daycarePkmnAddr, pokemon);
+                Self::writeNameToSave(save, layout, &layout.daycareNickAddr, pokemon.getNickname(), pokemon.getEncodedNickname())?;
+                Self::writeNameToSave(save, layout, &layout.daycareOTNAddr, pokemon.getOTN(), pokemon.getEncodedOTN())?;
+            },
+            None => save[layout.daycareInUseAddr] = 0
+        }
+
+        return Ok(true);
+    }
+
+    /// Writes the data shared by both party and boxed Pokemon.
+    /// 
+    /// This is the first 0x21 bytes of the Pokemon data structure.
+    fn writePokemonToSave(save: &mut Vec<u8>, currAddr: &usize, pokemon: &Pokemon) {
+        save[*currAddr] = *pokemon.getSpecies().getIndex() as u8;
+        save[currAddr+0x03] = *pokemon.getLevel() as u8;
+        save[currAddr+STATUS_OFF] = *pokemon.getStatusByte();
+        save[currAddr+TYPE_OFF..currAddr+TYPE_OFF+2].copy_from_slice(pokemon.getTypes());
+        save[currAddr+CATCH_RATE_OFF] = *pokemon.getCatchRate();
+
+        Self::writePokemonHPToSave(save, currAddr, pokemon.getHP());
+        Self::writePokemonExpToSave(save, currAddr, pokemon.getExp());
+        Self::writePokemonMovesToSave(save, currAddr, pokemon.getMoves());
+        Self::writePokemonOTIDToSave(save, currAddr, pokemon.getOTID());
+        Self::writePokemonEVsToSave(save, currAddr, pokemon);
+        Self::writePokemonIVsToSave(save, currAddr, pokemon);
+    }
+
+    /// Function for writing a Pokemons Original Trainers ID
+    fn writePokemonOTIDToSave(save: &mut Vec<u8>, currAddr: &usize, ot: &u16) {
+        save[currAddr+OT_OFF]   = (ot >> 8) as u8;
+        save[currAddr+OT_OFF+1] = *ot as u8;
+    }
+
+    /// Function for writing a Pokemons total experience
+    fn writePokemonExpToSave(save: &mut Vec<u8>, currAddr: &usize, exp: &u32) {
+        save[currAddr+EXP_OFF..currAddr+EXP_OFF+3].copy_from_slice(&exp.to_be_bytes()[1..]);
+    }
+
+    /// Function for writing the Pokemons current Health Points
+    fn writePokemonHPToSave(save: &mut Vec<u8>, currAddr: &usize, hp: &i16) {
+        save[currAddr+HP_OFF]   = (hp >> 8) as u8;
+        save[currAddr+HP_OFF+1] = *hp as u8;
+    }
+
+    /// Function for writing a Pokemons stats
+    /// 
+    /// **Note**: Only party Pokemon have their stats stored
+    fn writePokemonStatsToSave(save: &mut Vec<u8>, currAddr: &usize, pokemon: &Pokemon) {
+        let stats = pokemon.getStats();
+        let statArr: [u16;5] = [*stats.getHP(), *stats.getATK(), *stats.getDEF(), *stats.getSPD(), *stats.getSPC()];
+
+        for stat in 0..5 {
+            let currAddr = currAddr+STAT_OFF+(stat*2);
+            save[currAddr]   = (statArr[stat] >> 8) as u8;
+            save[currAddr+1] = statArr[stat] as u8;
+        }
+    }
+
+    /// Function for writing a Pokemons Effort Values
+    fn writePokemonEVsToSave(save: &mut Vec<u8>, currAddr: &usize, pokemon: &Pokemon) {
+        let evs = pokemon.getEVs();
+        let evArr: [u16;5] = [*evs.getHP(), *evs.getATK(), *evs.getDEF(), *evs.getSPD(), *evs.getSPC()];
+
+        for stat in 0..5 {
+            let currAddr = currAddr+EV_OFF+(stat*2);
+            save[currAddr]   = (evArr[stat] >> 8) as u8;
+            save[currAddr+1] = evArr[stat] as u8;
+        }
+    }
+
+    /// Function for writing a Pokemons moves, along with their PP and PP Ups
+    fn writePokemonMovesToSave(save: &mut Vec<u8>, currAddr: &usize, moves: &Vec<Move>) {
+        for (index, currMove) in moves.iter().enumerate() {
+            save[currAddr+MOVE_OFF+index] = *currMove.getIndex() as u8;
+            // The PP-Up count is stored in the top two bits, and the PP in the rest
+            save[currAddr+PP_OFF+index]   = (currMove.getPPUp() << 6) | (*currMove.getPP() as u8 & 0x3F);
+        }
+    }
+
+    /// Function for writing a Pokemons Individual Values
+    /// 
+    /// The HP IV is not stored, as it is derived from the other four.
+    fn writePokemonIVsToSave(save: &mut Vec<u8>, currAddr: &usize, pokemon: &Pokemon) {
+        let ivs = pokemon.getIVs();
+
+        save[currAddr+IV_OFF]   = ((ivs.getATK() << 4) | (ivs.getDEF() & 0xF)) as u8;
+        save[currAddr+IV_OFF+1] = ((ivs.getSPD() << 4) | (ivs.getSPC() & 0xF)) as u8;
+    }
+
+}
+
+#[cfg(test)]
+mod fileLoadingTests {
+    use super::*;
+
+    #[test]
+    fn load_InvalidFile() {
+        let fileName = "./test/POKEMON CRYSTAL.sav";
+        let saveFile = Save::load(fileName);
+
+        assert!(saveFile.is_err());
+        assert_eq!(saveFile.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: File does not seem to be a Gen 1 Save File");
+        
+    }
+
+    #[test]
+    fn load_ValidFile() {
+        let fileName = "./test/POKEMON BLUE.sav";
+        let saveFile = Save::load(fileName);
+
+        assert!(saveFile.is_ok());
+    }
+
+    #[test]
+    fn load_CorruptedMoney() {
+        let mut corruptSave = fs::read("./test/POKEMON BLUE.sav").unwrap();
+
+        // 0xAB isn't valid BCD
+        corruptSave[MONEY_ADDR+1] = 0xAB;
+        repairChecksums(&mut corruptSave, &INTERNATIONAL);
+
+        let saveFile = Save::from_bytes(corruptSave.clone());
+
+        // The save still loads, with the money flagged and its bytes left alone
+        let mut testSave = saveFile.unwrap();
+        assert_eq!(testSave.getMoney(), &0);
+        assert_eq!(testSave.checkMoney().unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Money is corrupted: Byte 1 (0xAB) is not a valid BCD value");
+        assert!(testSave.checkCoins().is_ok());
+        assert_eq!(testSave.to_bytes().unwrap(), corruptSave);
+
+        // Setting the money repairs it
+        testSave.setMoney(3_000).unwrap();
+        assert!(testSave.checkMoney().is_ok());
+        assert_eq!(Save::getMoneyFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL), Ok(3_000));
+    }
+
+    #[test]
+    fn load_CorruptedCoins() {
+        let mut corruptSave = fs::read("./test/POKEpi.sav").unwrap();
+
+        corruptSave[COINS_ADDR] = 0xF0;
+        repairChecksums(&mut corruptSave, &INTERNATIONAL);
+
+        let mut testSave = Save::from_bytes(corruptSave).unwrap();
+        assert_eq!(testSave.checkCoins().unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Coins are corrupted: Byte 0 (0xF0) is not a valid BCD value");
+
+        testSave.setCoins(50).unwrap();
+        assert!(testSave.checkCoins().is_ok());
+        assert_eq!(Save::getCoinsFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL), Ok(50));
+    }
+
+    #[test]
+    fn getParty_OTNamesFromList() {
+        let mut tradedSave = fs::read("./test/POKEMON BLUE.sav").unwrap();
+
+        // The Original Trainer names are a list after the party data, not part of each Pokemon
+        let otnAddress = INTERNATIONAL.partyAddr + INTERNATIONAL.partyOTNOff + INTERNATIONAL.nameLength;
+        tradedSave[otnAddress..otnAddress+4].copy_from_slice(&[0x91, 0x84, 0x83, 0x50]);
+        repairChecksums(&mut tradedSave, &INTERNATIONAL);
+
+        let testSave = Save::from_bytes(tradedSave).unwrap();
+        assert_eq!(testSave.getParty()[1].getOTN(), "RED");
+        assert_eq!(testSave.getParty()[0].getOTN(), testSave.getTrainerName());
+        assert_eq!(testSave.getParty()[2].getOTN(), testSave.getTrainerName());
+    }
+
+    #[test]
+    fn print_SmallParty() {
+        // A new save only has one Pokemon in its party
+        let testSave = Save::new();
+        assert_eq!(testSave.getParty().len(), 1);
+
+        testSave.print();
+    }
+
+    #[test]
+    fn load_UnknownContainer() {
+        let saveFile = Save::from_bytes(vec![0; SAVE_SIZE + 1]);
+
+        assert_eq!(saveFile.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: File is 32769 bytes, which isn't a known save format");
+    }
+
+    #[test]
+    fn load_NonexistentFile() {
+        let fileName = "./test/Nonexistent File.sav";
+        let saveFile = Save::load(fileName);
+
+        assert!(saveFile.is_err());
+        assert_eq!(saveFile.unwrap_err(), format!("\u{1b}[0;31mError\u{1b}[0m: Save \"{}\" does not exist",fileName));
+        
+    }
+
+}
+
+#[cfg(test)]
+mod fileWritingTests {
+    use super::*;
+    use super::super::utils::integrityCheck;
+
+    #[test]
+    fn to_bytes_KeepsSize() {
+        let testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        assert_eq!(testSave.to_bytes().unwrap().len(), SAVE_SIZE);
+    }
+
+    #[test]
+    fn to_bytes_UneditedIsIdentical() {
+        let testSaveList = ["./test/POKEMON YELLOW.sav", "./test/POKEMON YELLOW 2.sav", "./test/POKEMON BLUE.sav", "./test/Pokeblue.sav", "./test/POKEpi.sav"];
+
+        for save in testSaveList {
+            let originalSave = fs::read(save).unwrap();
+            let testSave = Save::load(save).unwrap();
+
+            assert!(testSave.getDirtyRegions().is_empty());
+            assert_eq!(testSave.to_bytes().unwrap(), originalSave);
+        }
+    }
+
+    #[test]
+    fn to_bytes_OnlyDirtyRegionsWritten() {
+        let originalSave = fs::read("./test/POKEMON YELLOW.sav").unwrap();
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        testSave.setMoney(1000).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        assert_eq!(testSave.getDirtyRegions(), &vec![Region::Money]);
+        // The party is not dirty, so it should be untouched
+        assert_eq!(writtenSave[PARTY_ADDR..PARTY_ADDR+0x194], originalSave[PARTY_ADDR..PARTY_ADDR+0x194]);
+        assert_ne!(writtenSave[MONEY_ADDR..MONEY_ADDR+3], originalSave[MONEY_ADDR..MONEY_ADDR+3]);
+    }
+
+    #[test]
+    fn markDirty_FailedSetterIsClean() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        assert!(testSave.setPartyPokemonIV_ATK(0, 16).is_err());
+        assert!(testSave.setMoney(1_000_000).is_err());
+
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+    #[test]
+    fn write_UneditedIsIdentical() {
+        let testSave = Save::load("./test/POKEpi.sav").unwrap();
+        let outputPath = std::env::temp_dir().join("PKRust_write_UneditedIsIdentical.sav");
+
+        testSave.write(outputPath.to_str().unwrap()).unwrap();
+
+        assert_eq!(fs::read(&outputPath).unwrap(), fs::read("./test/POKEpi.sav").unwrap());
+
+        fs::remove_file(outputPath).unwrap();
+    }
+
+    #[test]
+    fn to_bytes_Money() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        testSave.setMoney(123_456).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        assert_eq!(Save::getMoneyFromSave(&writtenSave, &INTERNATIONAL), Ok(123_456));
+    }
+
+    #[test]
+    fn to_bytes_TrainerName() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        testSave.setTrainerName(String::from("Brock")).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        assert_eq!(textDecode(&Save::getNameFromSave(&writtenSave, &INTERNATIONAL)), "Brock");
+    }
+
+    #[test]
+    fn to_bytes_TrainerID() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        testSave.setID(54321);
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        assert_eq!(Save::getTrainerIDFromSave(&writtenSave, &INTERNATIONAL), 54321);
+    }
+
+    #[test]
+    fn to_bytes_PartyPokemon() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        testSave.setPartyPokemonLevel(1, 42).unwrap();
+        testSave.setPartyPokemonIV_ATK(1, 15).unwrap();
+        testSave.setPartyPokemonEV_SPC(1, 1234).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        let party = Save::getPartyFromSave(&writtenSave, &INTERNATIONAL);
+
+        assert_eq!(party.len(), testSave.getParty().len());
+        assert_eq!(party[1].getLevel(), &42);
+        assert_eq!(party[1].getIVs().getATK(), &15);
+        assert_eq!(party[1].getEVs().getSPC(), &1234);
+    }
+
+    #[test]
+    fn to_bytes_UneditedPokemon() {
+        let testSaveList = ["./test/POKEMON YELLOW.sav", "./test/POKEMON YELLOW 2.sav", "./test/POKEMON BLUE.sav", "./test/Pokeblue.sav", "./test/POKEpi.sav"];
+
+        for save in testSaveList {
+            let testSave = Save::load(save).unwrap();
+            let writtenSave = testSave.to_bytes().unwrap();
+
+            let party = Save::getPartyFromSave(&writtenSave, &INTERNATIONAL);
+            let pc = Save::getPCBoxesFromSave(&writtenSave, &INTERNATIONAL);
+
+            for (index, pokemon) in testSave.getParty().iter().enumerate() {
+                assert_eq!(party[index].getDetails(), pokemon.getDetails());
+            }
+
+            for (pcBox, currBox) in testSave.getPCBoxes().iter().enumerate() {
+                assert_eq!(pc[pcBox].len(), currBox.len());
+                for (index, pokemon) in currBox.iter().enumerate() {
+                    assert_eq!(pc[pcBox][index].getDetails(), pokemon.getDetails());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn to_bytes_KeepsUneditedNames() {
+        let mut editedSave = fs::read("./test/POKEMON BLUE.sav").unwrap();
+        let nickAddr = PARTY_ADDR + NICK_OFF;
+
+        // "F'd", followed by 0x00, which isn't a character
+        let nickname = [0x85, 0xBB, 0x00, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50];
+        editedSave[nickAddr..nickAddr+11].copy_from_slice(&nickname);
+        repairChecksums(&mut editedSave, &INTERNATIONAL);
+
+        let mut testSave = Save::from_bytes(editedSave).unwrap();
+        assert_eq!(testSave.getParty()[0].getNickname(), "F'd ");
+
+        testSave.setPartyPokemonNick(1, String::from("Farfetch'd")).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        // The unedited name is written back as it was, and the edited one is encoded
+        assert_eq!(writtenSave[nickAddr..nickAddr+11], nickname);
+        assert_eq!(writtenSave[nickAddr+11..nickAddr+22], [0x85, 0xA0, 0xB1, 0xA5, 0xA4, 0xB3, 0xA2, 0xA7, 0xBB, 0x50, 0x50]);
+    }
+
+    #[test]
+    fn to_bytes_PassesIntegrityCheck() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        testSave.setMoney(1).unwrap();
+        testSave.setPartyPokemonNick(0, String::from("SPARKY")).unwrap();
+
+        assert_eq!(integrityCheck(&testSave.to_bytes().unwrap(), &INTERNATIONAL), true);
+    }
+
+    #[test]
+    fn write_MatchesBytes() {
+        let testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
+        let outputPath = std::env::temp_dir().join("PKRust_write_MatchesBytes.sav");
+
+        let writeResult = testSave.write(outputPath.to_str().unwrap());
+
+        assert!(writeResult.is_ok());
+        assert_eq!(fs::read(&outputPath).unwrap(), testSave.to_bytes().unwrap());
+
+        fs::remove_file(outputPath).unwrap();
+    }
+
+    #[test]
+    fn to_bytes_KeepsTruncatedContainer() {
+        let mut testSave = Save::load("./test/Pokeblue.sav").unwrap();
+
+        testSave.setMoney(1_000).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        assert_eq!(testSave.getContainer().to_string(), "Truncated SRAM (31315 of 32768 bytes)");
+        assert_eq!(writtenSave.len(), fs::read("./test/Pokeblue.sav").unwrap().len());
+        assert_eq!(Save::getMoneyFromSave(&writtenSave, &INTERNATIONAL), Ok(1_000));
+    }
+
+    #[test]
+    fn to_bytes_KeepsRTCFooter() {
+        // Yellow has no clock, but emulators still add a footer to some saves
+        let mut saveFile = fs::read("./test/POKEMON YELLOW.sav").unwrap();
+        let footer: Vec<u8> = (0..0x30).collect();
+        saveFile.extend_from_slice(&footer);
+
+        let mut testSave = Save::from_bytes(saveFile.clone()).unwrap();
+
+        assert_eq!(testSave.getContainer().to_string(), "32 KiB SRAM with a 48 byte RTC footer");
+        assert_eq!(testSave.to_bytes().unwrap(), saveFile);
+
+        testSave.setMoney(1_000).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        assert_eq!(writtenSave.len(), SAVE_SIZE + 0x30);
+        assert_eq!(writtenSave[SAVE_SIZE..], footer[..]);
+        assert_eq!(integrityCheck(&writtenSave, &INTERNATIONAL), true);
+    }
+
+}
+
+#[cfg(test)]
+mod trainerTests {
+    use super::*;
+
+    #[test]
+    fn setTrainerName_Correct() {
+        // This is the save we'll be testing with
+        let mut currSave = Save::new();
+
+        // This is the trainer name we'll be setting
+        let newName = String::from("Brock");
+
+        // Now we change the name
+        let nameChangeResult = currSave.setTrainerName(newName);
+
+        // Once Gotten, we expect it to be Ok() and not Err()
+        assert!(nameChangeResult.is_ok());
+        // And we expect the unwrapped version of the name to be true
+        assert_eq!(nameChangeResult.unwrap(), true);
+    }
+
+    #[test]
+    fn setTrainerName_Incorrect() {
+        // This is the save we'll be testing with
+        let mut currSave = Save::new();
+
+        // This is the trainer name we'll be setting.
+        // The maximum length for a name in gen 1 is 7 chars.
+        // Thus we will be testing with more than 7
+        let newName = String::from("Professor Oak");
+
+        // Now we change the name
+        let nameChangeResult = currSave.setTrainerName(newName);
+
+        // Once Gotten, we expect it to be Ok() and not Err()
+        assert!(nameChangeResult.is_err());
+        // And we expect the unwrapped version of the name to be true
+        assert_eq!(nameChangeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Name \"Professor Oak\" is over 7 characters.");
+    }
+
+    #[test]
+    fn getRivalName_Correct() {
+        let testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        assert_eq!(testSave.getRivalName(), "GARY");
+    }
+
+    #[test]
+    fn setRivalName_Correct() {
+        let mut currSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        let nameChangeResult = currSave.setRivalName(String::from("Blue"));
+
+        assert!(nameChangeResult.is_ok());
+        assert_eq!(nameChangeResult.unwrap(), true);
+        assert_eq!(textDecode(&Save::getRivalNameFromSave(&currSave.to_bytes().unwrap(), &INTERNATIONAL)), "Blue");
+    }
+
+    #[test]
+    fn setRivalName_Incorrect() {
+        let mut currSave = Save::new();
+
+        // The maximum length for a name in gen 1 is 7 chars.
+        let nameChangeResult = currSave.setRivalName(String::from("Professor Oak"));
+
+        assert!(nameChangeResult.is_err());
+        assert_eq!(nameChangeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Rival name \"Professor Oak\" is over 7 characters.");
+    }
+
+    #[test]
+    fn setMoney_Correct() {
+        let mut testSave = Save::new();
+
+        let newMoneyAmount:u32 = 1000;
+
+        let moneyChangeResult = testSave.setMoney(newMoneyAmount);
+
+        assert!(moneyChangeResult.is_ok());
+        assert_eq!(moneyChangeResult.unwrap(), true);
+    }
+
+    #[test]
+    fn setMoney_Incorrect() {
+        let mut testSave = Save::new();
+
+        let newMoneyAmount:u32 = 1_000_000;
+
+        let moneyChangeResult = testSave.setMoney(newMoneyAmount);
+
+        assert!(moneyChangeResult.is_err());
+        assert_eq!(moneyChangeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Amount \"1000000\" is over allowed maximum 999,999.") 
+    }
+
+    #[test]
+    fn getCoins_Correct() {
+        let testSave = Save::load("./test/POKEpi.sav").unwrap();
+
+        assert_eq!(testSave.getCoins(), &689);
+    }
+
+    #[test]
+    fn setCoins_Correct() {
+        let mut testSave = Save::load("./test/POKEpi.sav").unwrap();
+
+        let coinChangeResult = testSave.setCoins(9_999);
+
+        assert!(coinChangeResult.is_ok());
+        assert_eq!(Save::getCoinsFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL), Ok(9_999));
+    }
+
+    #[test]
+    fn setCoins_Incorrect() {
+        let mut testSave = Save::new();
+
+        let coinChangeResult = testSave.setCoins(10_000);
+
+        assert!(coinChangeResult.is_err());
+        assert_eq!(coinChangeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Amount \"10000\" is over allowed maximum 9,999.");
+    }
+
+    #[test]
+    fn setID_Correct() {
+        let mut testSave = Save::new();
+
+        let newID:u16 = 12345;
+
+        testSave.setID(newID);
+
+        assert_eq!(testSave.getTrainerID(), &newID);
+    }
+
+}
+
+#[cfg(test)]
+mod itemTests {
+    use super::*;
+
+    #[test]
+    fn getBag_Correct() {
+        let testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+        let bag = testSave.getBag().getItems();
+
+        assert_eq!(bag.len(), 5);
+        assert_eq!(bag[0].getName(), "Potion");
+        assert_eq!(bag[0].getQuantity(), &2);
+        assert_eq!(bag[4].getName(), "Escape Rope");
+    }
+
+    #[test]
+    fn getPCItems_Correct() {
+        let testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
+        let pcItems = testSave.getPCItems().getItems();
+
+        assert_eq!(pcItems.len(), 10);
+        assert_eq!(pcItems[0].getName(), "Rare Candy");
+        assert_eq!(pcItems[0].getQuantity(), &3);
+    }
+
+    #[test]
+    fn addBagItem_Written() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        testSave.addBagItem(0x01, 10).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        let bag = Save::getItemsFromSave(&writtenSave, &BAG_ADDR, BAG_CAPACITY);
+
+        assert_eq!(bag.len(), 6);
+        assert_eq!(bag[5].getName(), "Master Ball");
+        assert_eq!(bag[5].getQuantity(), &10);
+        assert_eq!(writtenSave[BAG_ADDR + 1 + 12], 0xFF);
+    }
+
+    #[test]
+    fn addBagItem_IncorrectFull() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
+
+        let addResult = testSave.addBagItem(0x14, 1);
+
+        assert!(addResult.is_err());
+        assert_eq!(addResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Inventory is full, it can only hold 20 items");
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+    #[test]
+    fn removePCItem_Written() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
+
+        testSave.removePCItem(0x28, 3).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        let pcItems = Save::getItemsFromSave(&writtenSave, &PC_ITEMS_ADDR, PC_ITEMS_CAPACITY);
+
+        assert_eq!(pcItems.len(), 9);
+        assert_eq!(pcItems[0].getName(), "Helix Fossil");
+    }
+
+    #[test]
+    fn setBagItemQuantity_IncorrectOver() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        let quantityResult = testSave.setBagItemQuantity(0, 100);
+
+        assert!(quantityResult.is_err());
+        assert_eq!(quantityResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Quantity \"100\" is over allowed maximum 99");
+    }
+
+}
+
+#[cfg(test)]
+mod pokedexTests {
+    use super::*;
+
+    #[test]
+    fn getPokedex_PartyIsOwned() {
+        let testSaveList = ["./test/POKEMON YELLOW.sav", "./test/POKEMON YELLOW 2.sav", "./test/POKEMON BLUE.sav", "./test/Pokeblue.sav", "./test/POKEpi.sav"];
+
+        for save in testSaveList {
+            let testSave = Save::load(save).unwrap();
+
+            // Every Pokemon in the party has to have been caught at some point
+            for pokemon in testSave.getParty() {
+                assert_eq!(testSave.getPokedex().isOwned(pokemon.getSpecies()), true);
+            }
+        }
+    }
+
+    #[test]
+    fn setPokedexOwned_Written() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+        // Mew
+        let mew = Species::parse(0x15).unwrap();
+
+        testSave.setPokedexOwned(0x15, true).unwrap();
+        let writtenDex = Save::getPokedexFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL);
+
+        assert_eq!(writtenDex.isOwned(&mew), true);
+        assert_eq!(writtenDex.isSeen(&mew), true);
+    }
+
+    #[test]
+    fn setPokedexSeen_IncorrectSpecies() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        let seenResult = testSave.setPokedexSeen(0x00, true);
+
+        assert!(seenResult.is_err());
+        assert_eq!(seenResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Species with ID 0x00 not found.");
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+    #[test]
+    fn completePokedex_Written() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        testSave.completePokedex();
+        let writtenDex = Save::getPokedexFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL);
+
+        assert_eq!(writtenDex.getOwnedCount(), 151);
+        assert_eq!(writtenDex.getSeenCount(), 151);
+    }
+
+}
+
+#[cfg(test)]
+mod trainerCardTests {
+    use super::*;
+
+    #[test]
+    fn getBadges_Correct() {
+        let blueSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+        let yellowSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        assert_eq!(blueSave.getBadges().getCount(), 0);
+        assert_eq!(yellowSave.getBadges().getCount(), 8);
+    }
+
+    #[test]
+    fn setBadge_Written() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        testSave.setBadge(0, true).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        assert_eq!(writtenSave[BADGES_ADDR], 0b0000_0001);
+    }
+
+    #[test]
+    fn setBadge_Incorrect() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        let badgeResult = testSave.setBadge(8, true);
+
+        assert!(badgeResult.is_err());
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+    #[test]
+    fn getPlayTime_Correct() {
+        let testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        assert_eq!(testSave.getPlayTime().to_string(), "167:55:44");
+        assert_eq!(testSave.getPlayTime().isMaxed(), false);
+    }
+
+    #[test]
+    fn setPlayTime_Written() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        testSave.setPlayTime(12, 34, 56, 7).unwrap();
+        let writtenTime = Save::getPlayTimeFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL);
+
+        assert_eq!(writtenTime.to_string(), "12:34:56");
+        assert_eq!(writtenTime.getFrames(), &7);
+    }
+
+    #[test]
+    fn setPlayTime_Incorrect() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        let timeResult = testSave.setPlayTime(12, 60, 0, 0);
+
+        assert!(timeResult.is_err());
+        assert_eq!(testSave.getPlayTime().to_string(), "167:55:44");
+    }
+
+    #[test]
+    fn getOptions_Correct() {
+        let testSave = Save::load("./test/POKEpi.sav").unwrap();
+
+        assert_eq!(testSave.getOptions().getTextSpeed(), 1);
+        assert_eq!(testSave.getOptions().getBattleStyle(), BattleStyle::Set);
+        assert_eq!(testSave.getOptions().getBattleAnimation(), true);
+    }
+
+    #[test]
+    fn setOptions_Written() {
+        let mut testSave = Save::load("./test/POKEpi.sav").unwrap();
+
+        testSave.setTextSpeed(5).unwrap();
+        testSave.setBattleAnimation(false);
+        testSave.setBattleStyle(BattleStyle::Shift);
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        assert_eq!(writtenSave[OPTIONS_ADDR], 0b1000_0101);
+    }
+
+}
+
+#[cfg(test)]
+mod hallOfFameTests {
+    use super::*;
+
+    #[test]
+    fn getHallOfFame_Correct() {
+        let testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+        let hallOfFame = testSave.getHallOfFame();
+
+        assert_eq!(hallOfFame.getCount(), &32);
+        assert_eq!(hallOfFame.getRecords().len(), 32);
+        assert_eq!(hallOfFame.getRecords()[0][2].getSpecies().getName(), "Pikachu");
+        assert_eq!(hallOfFame.getRecords()[0][2].getLevel(), &62);
+    }
+
+    #[test]
+    fn getHallOfFame_PartialTeam() {
+        let testSave = Save::load("./test/POKEpi.sav").unwrap();
+        let hallOfFame = testSave.getHallOfFame();
+
+        assert_eq!(hallOfFame.getRecords().len(), 1);
+        assert_eq!(hallOfFame.getRecords()[0].len(), 5);
+    }
+
+    #[test]
+    fn getHallOfFame_UnknownSpecies() {
+        let mut glitchSave = fs::read("./test/POKEMON YELLOW 2.sav").unwrap();
+
+        // 0x1F isn't a species, but the team only ends at 0xFF
+        glitchSave[INTERNATIONAL.hofEntryAddr(0, 2)] = 0x1F;
+        repairChecksums(&mut glitchSave, &INTERNATIONAL);
+
+        let mut testSave = Save::from_bytes(glitchSave).unwrap();
+        let team = &testSave.getHallOfFame().getRecords()[0];
+        assert_eq!(team.len(), 6);
+        assert_eq!(team[2].getSpecies().getName(), "Unknown");
+        assert_eq!(team[2].getSpecies().getIndex(), &0x1F);
+
+        // Editing another entry writes the whole team back, without cutting it at the unknown species
+        testSave.setHallOfFameLevel(0, 5, 55).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+        let writtenHoF = Save::getHallOfFameFromSave(&writtenSave, &INTERNATIONAL);
+
+        assert_eq!(writtenSave[INTERNATIONAL.hofEntryAddr(0, 2)], 0x1F);
+        assert_eq!(writtenHoF.getRecords()[0].len(), 6);
+        assert_eq!(writtenHoF.getRecords()[0][5].getLevel(), &55);
+    }
+
+    #[test]
+    fn clearHallOfFame_Written() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        testSave.clearHallOfFame().unwrap();
+        let writtenHoF = Save::getHallOfFameFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL);
+
+        assert_eq!(writtenHoF.getCount(), &0);
+        assert!(writtenHoF.getRecords().is_empty());
+    }
+
+    #[test]
+    fn setHallOfFameLevel_Written() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
+
+        testSave.setHallOfFameLevel(0, 5, 55).unwrap();
+        testSave.setHallOfFameSpecies(0, 5, 0x15).unwrap();
+        let writtenHoF = Save::getHallOfFameFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL);
+
+        assert_eq!(writtenHoF.getRecords()[0][5].getLevel(), &55);
+        assert_eq!(writtenHoF.getRecords()[0][5].getSpecies().getName(), "Mew");
+    }
+
+    #[test]
+    fn setHallOfFameNickname_IncorrectRecord() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
+
+        let nicknameResult = testSave.setHallOfFameNickname(1, 0, String::from("SPARKY"));
+
+        assert!(nicknameResult.is_err());
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+}
+
+#[cfg(test)]
+mod positionTests {
+    use super::*;
+
+    #[test]
+    fn getPosition_Correct() {
+        let testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+        let position = testSave.getPosition();
+
+        assert_eq!(position.getMap().getName(), "Red's House 1F");
+        assert_eq!(position.getX(), &5);
+        assert_eq!(position.getY(), &5);
+        assert_eq!(position.getLastMap().getName(), "Pallet Town");
+    }
+
+    #[test]
+    fn getPosition_LastBlackout() {
+        let testSave = Save::load("./test/POKEpi.sav").unwrap();
+        let position = testSave.getPosition();
+
+        assert_eq!(position.getMap().getName(), "Indigo Plateau Lobby");
+        assert_eq!(position.getLastBlackoutMap().getName(), "Indigo Plateau");
+    }
+
+    #[test]
+    fn teleport_Written() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        testSave.teleport(0x25, 5, 5).unwrap();
+        let writtenPosition = Save::getPositionFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL);
+
+        // Standing at the same spot as the Yellow save, so the view pointer should match it
+        assert_eq!(writtenPosition.getMap().getIndex(), &0x25);
+        assert_eq!(writtenPosition.getXBlock(), &1);
+        assert_eq!(writtenPosition.getYBlock(), &1);
+        assert_eq!(writtenPosition.getViewPointer(), &0xC709);
+    }
+
+    #[test]
+    fn teleport_OutdoorLastMap() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        testSave.teleport(0x01, 5, 5).unwrap();
+        let writtenPosition = Save::getPositionFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL);
+
+        assert_eq!(writtenPosition.getLastMap().getName(), "Viridian City");
+    }
+
+    #[test]
+    fn teleport_Incorrect() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        let teleportResult = testSave.teleport(0x00, 0, 18);
+
+        assert!(teleportResult.is_err());
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+}
+
+#[cfg(test)]
+mod eventFlagTests {
+    use super::*;
+
+    #[test]
+    fn isFlagSet_Correct() {
+        let testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        assert_eq!(testSave.isFlagSet("BEAT_BROCK"), Ok(true));
+        assert_eq!(testSave.isFlagSet("BEAT_ROUTE12_SNORLAX"), Ok(true));
+        assert_eq!(testSave.isFlagSet("BEAT_ROUTE16_SNORLAX"), Ok(false));
+    }
+
+    #[test]
+    fn isFlagSet_NoBadges() {
+        let testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        assert_eq!(testSave.isFlagSet("GOT_POKEDEX"), Ok(true));
+        assert_eq!(testSave.isFlagSet("BEAT_BROCK"), Ok(false));
+    }
+
+    #[test]
+    fn setFlag_Written() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        testSave.setFlag("BEAT_MEWTWO", false).unwrap();
+        testSave.setFlagByIndex(FlagKind::Missable, 0x00, false).unwrap();
+        let writtenFlags = Save::getEventFlagsFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL);
+
+        assert_eq!(writtenFlags.isSetByName("BEAT_MEWTWO"), Ok(false));
+        assert_eq!(writtenFlags.isSetByName("PALLET_TOWN_OAK"), Ok(false));
+        // Neighbouring flags are left alone
+        assert_eq!(writtenFlags.isSet(FlagKind::Event, 0x8C0), Ok(false));
+        assert_eq!(writtenFlags.isSetByName("LYING_OLD_MAN"), Ok(true));
+    }
+
+    #[test]
+    fn setFlag_Incorrect() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        let flagResult = testSave.setFlag("BEAT_GARY", true);
+
+        assert!(flagResult.is_err());
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+    #[test]
+    fn setFlag_ResetArticunoAndMoltres() {
+        let mut testSave = Save::load("./test/POKEpi.sav").unwrap();
+
+        assert_eq!(testSave.isFlagSet("BEAT_ARTICUNO"), Ok(true));
+        assert_eq!(testSave.isFlagSet("ARTICUNO"), Ok(true));
+        assert_eq!(testSave.isFlagSet("BEAT_MOLTRES"), Ok(true));
+        assert_eq!(testSave.isFlagSet("MOLTRES"), Ok(true));
+
+        // A bird comes back once it hasn't been beaten and its object is shown again
+        testSave.setFlag("BEAT_ARTICUNO", false).unwrap();
+        testSave.setFlag("ARTICUNO", false).unwrap();
+        testSave.setFlag("BEAT_MOLTRES", false).unwrap();
+        testSave.setFlag("MOLTRES", false).unwrap();
+
+        let writtenSave = Save::from_bytes(testSave.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(writtenSave.isFlagSet("BEAT_ARTICUNO"), Ok(false));
+        assert_eq!(writtenSave.isFlagSet("ARTICUNO"), Ok(false));
+        assert_eq!(writtenSave.isFlagSet("BEAT_MOLTRES"), Ok(false));
+        assert_eq!(writtenSave.isFlagSet("MOLTRES"), Ok(false));
+        // Zapdos is left alone
+        assert_eq!(writtenSave.isFlagSet("BEAT_ZAPDOS"), Ok(true));
+        assert_eq!(writtenSave.isFlagSet("ZAPDOS"), Ok(true));
+    }
+
+    #[test]
+    fn setFlag_RedBlueOnlyOnYellow() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        let flagResult = testSave.setFlag("MEWTWO", false);
+
+        assert!(flagResult.is_err());
+        assert_eq!(flagResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Flag \"MEWTWO\" is only listed for Red/Blue, but this save is from Yellow");
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+}
+
+#[cfg(test)]
+mod pcBoxTests {
+    use super::*;
+    use super::super::utils::{calculateBoxChecksum, integrityCheck};
+
+    #[test]
+    fn getPCBoxes_CurrentBoxOverlay() {
+        // Box 8 is open, and its copy in bank 3 is empty
+        let testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        assert_eq!(testSave.getCurrentBox(), &7);
+        assert_eq!(testSave.getPCBoxes()[7].len(), 9);
+    }
+
+    #[test]
+    fn getPCBoxes_CurrentBoxInBank3() {
+        let testSave = Save::load("./test/POKEpi.sav").unwrap();
+
+        assert_eq!(testSave.getCurrentBox(), &10);
+        assert_eq!(testSave.getPCBoxes()[10].len(), 18);
+    }
+
+    #[test]
+    fn getPCBoxes_Uninitialised() {
+        let mut blankBanks = fs::read("./test/POKEMON BLUE.sav").unwrap();
+
+        // The player has never switched box, so the banks still hold whatever was in SRAM
+        assert_eq!(blankBanks[CURRENT_BOX_ADDR] & 0x80, 0);
+        for byte in &mut blankBanks[0x4000..0x8000] {
+            *byte = 0xFF;
+        }
+
+        let testSave = Save::from_bytes(blankBanks).unwrap();
+
+        // Only the working copy of the current box is read
+        for (pcBox, currBox) in testSave.getPCBoxes().iter().enumerate() {
+            if pcBox == *testSave.getCurrentBox() {
+                assert_eq!(currBox.len(), 4);
+            } else {
+                assert!(currBox.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn getPCBoxes_CountClamped() {
+        let mut overfullBox = fs::read("./test/POKEMON YELLOW 2.sav").unwrap();
+
+        // The current box is full, so a larger count would run past the species list
+        assert_eq!(overfullBox[CURRENT_BOX_DATA_ADDR], 20);
+        overfullBox[CURRENT_BOX_DATA_ADDR] = 0xFF;
+        repairChecksums(&mut overfullBox, &INTERNATIONAL);
+
+        let testSave = Save::from_bytes(overfullBox).unwrap();
+        assert_eq!(testSave.getPCBoxes()[*testSave.getCurrentBox()].len(), BOX_CAPACITY);
+    }
+
+    #[test]
+    fn getPCBoxes_StatsCalculated() {
+        let testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        for pokemon in &testSave.getPCBoxes()[7] {
+            assert!(*pokemon.getStats().getHP() > 0);
+            assert!(pokemon.hasCalculatedStats());
+        }
+    }
+
+    #[test]
+    fn getParty_StatsMatchCalculated() {
+        // Every party Pokemon in this save has had its stats recalculated since it last gained Stat Experience
+        let testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
+
+        for pokemon in testSave.getParty() {
+            assert!(pokemon.hasCalculatedStats());
+        }
+
+        // This save has been edited to give its Pokemon higher stats than they could have
+        let editedSave = Save::load("./test/Pokeblue.sav").unwrap();
+        assert_eq!(editedSave.getParty()[0].hasCalculatedStats(), false);
+    }
+
+    #[test]
+    fn setCurrentBox_Written() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        testSave.setCurrentBox(0).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        // The working copy now holds box 1, and box 8 has been copied back to its bank
+        assert_eq!(writtenSave[CURRENT_BOX_ADDR], 0x80);
+        assert_eq!(writtenSave[CURRENT_BOX_DATA_ADDR], 15);
+        assert_eq!(Save::getBoxFromSave(&writtenSave, &INTERNATIONAL, &(PC_ADDR + BANK_SIZE + BOX_SIZE)).len(), 9);
+        assert_eq!(Save::getPCBoxesFromSave(&writtenSave, &INTERNATIONAL)[7].len(), 9);
+        assert!(integrityCheck(&writtenSave, &INTERNATIONAL));
+        assert_eq!(writtenSave[PC_ADDR + BANK_SIZE + BOX_CHECKSUM_OFF + 1], calculateBoxChecksum(&writtenSave, &INTERNATIONAL, 7));
+    }
+
+    #[test]
+    fn setCurrentBox_Incorrect() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        let boxResult = testSave.setCurrentBox(12);
+
+        assert!(boxResult.is_err());
+        assert_eq!(boxResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Box \"12\" does not exist, there are only 12 boxes");
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+}
+
+#[cfg(test)]
+mod daycareTests {
+    use super::*;
+    use super::super::utils::integrityCheck;
+
+    #[test]
+    fn getDaycare_Empty() {
+        let testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        assert!(testSave.getDaycare().is_none());
+    }
+
+    #[test]
+    fn getDaycareFromSave_Correct() {
+        // The Yellow save still has the last daycare Pokemon in it, so we mark it in use
+        let mut testSave = fs::read("./test/POKEMON YELLOW.sav").unwrap();
+        testSave[DAYCARE_IN_USE_ADDR] = 1;
+
+        let daycare = Save::getDaycareFromSave(&testSave, &INTERNATIONAL).unwrap();
+
+        assert_eq!(daycare.getSpecies().getName(), "Mankey");
+        assert_eq!(daycare.getNickname(), "MANKEY");
+        assert_eq!(daycare.getLevel(), &46);
+    }
+
+    #[test]
+    fn depositToDaycare_Written() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+        let partySize = testSave.getParty().len();
+        let speciesName = testSave.getParty()[1].getSpecies().getName().clone();
+
+        testSave.depositToDaycare(1).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+        let daycare = Save::getDaycareFromSave(&writtenSave, &INTERNATIONAL).unwrap();
+
+        assert_eq!(daycare.getSpecies().getName(), &speciesName);
+        assert_eq!(Save::getPartyFromSave(&writtenSave, &INTERNATIONAL).len(), partySize - 1);
+        assert!(integrityCheck(&writtenSave, &INTERNATIONAL));
+    }
+
+    #[test]
+    fn depositToDaycare_LastPokemon() {
+        let mut testSave = Save::new();
+
+        let depositResult = testSave.depositToDaycare(0);
+
+        assert!(depositResult.is_err());
+        assert_eq!(depositResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: The last Pokemon in the party can't be left at the daycare");
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+    #[test]
+    fn withdrawFromDaycare_Correct() {
+        let mut testSave = Save::new();
+        testSave.setDaycare(Some(Pokemon::new()));
+
+        let withdrawResult = testSave.withdrawFromDaycare();
+
+        assert!(withdrawResult.is_ok());
+        assert!(testSave.getDaycare().is_none());
+        assert_eq!(testSave.getParty().len(), 2);
+    }
+
+    #[test]
+    fn withdrawFromDaycare_Empty() {
+        let mut testSave = Save::new();
+
+        let withdrawResult = testSave.withdrawFromDaycare();
+
+        assert!(withdrawResult.is_err());
+        assert_eq!(withdrawResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: There is no Pokemon in the daycare");
+    }
+
+    #[test]
+    fn setDaycarePokemonLevel_Written() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+        testSave.depositToDaycare(1).unwrap();
+
+        testSave.setDaycarePokemonLevel(50).unwrap();
+        testSave.setDaycarePokemonNick(String::from("SITTER")).unwrap();
+        let daycare = Save::getDaycareFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL).unwrap();
+
+        assert_eq!(daycare.getLevel(), &50);
+        assert_eq!(daycare.getNickname(), "SITTER");
+    }
+
+    #[test]
+    fn setDaycarePokemonExp_Written() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+        testSave.depositToDaycare(1).unwrap();
+        let levelExp = testSave.getDaycare().as_ref().unwrap().getSpecies().getGrowthRate().as_ref().unwrap().expForLevel(60);
+
+        testSave.setDaycarePokemonExp(levelExp).unwrap();
+        assert!(testSave.getDirtyRegions().contains(&Region::Daycare));
+        let daycare = Save::getDaycareFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL).unwrap();
+
+        assert_eq!(daycare.getLevel(), &60);
+        assert_eq!(daycare.getExp(), &levelExp);
+    }
+
+    #[test]
+    fn setDaycarePokemonStats_Written() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+        testSave.depositToDaycare(1).unwrap();
+
+        testSave.setDaycarePokemonStatus(StatusCondition::Asleep(2)).unwrap();
+        testSave.setDaycarePokemonEV_ATK(1000).unwrap();
+        testSave.setDaycarePokemonEV_SPC(65535).unwrap();
+        testSave.setDaycarePokemonIV_DEF(7).unwrap();
+        testSave.setDaycarePokemonIV_SPD(15).unwrap();
+        let daycare = Save::getDaycareFromSave(&testSave.to_bytes().unwrap(), &INTERNATIONAL).unwrap();
+
+        assert_eq!(daycare.getStatus(), StatusCondition::Asleep(2));
+        assert_eq!(daycare.getEVs().getATK(), &1000);
+        assert_eq!(daycare.getEVs().getSPC(), &65535);
+        assert_eq!(daycare.getIVs().getDEF(), &7);
+        assert_eq!(daycare.getIVs().getSPD(), &15);
+
+        // The HP IV that is read back comes from the other four, so it has to match the one held in memory
+        assert_eq!(daycare.getIVs().getHP(), testSave.getDaycare().as_ref().unwrap().getIVs().getHP());
+    }
+
+    #[test]
+    fn setDaycarePokemonIV_HP_Derived() {
+        let mut testSave = Save::new();
+        testSave.setDaycare(Some(Pokemon::new()));
+
+        let ivResult = testSave.setDaycarePokemonIV_HP(15);
+
+        assert_eq!(ivResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: HP IV can't be set to \"15\", as it is derived from the ATK, DEF, SPD and SPC IVs");
+        assert_eq!(testSave.getDaycare().as_ref().unwrap().getIVs().getHP(), &0);
+    }
+
+    #[test]
+    fn setDaycarePokemonIV_IncorrectOver() {
+        let mut testSave = Save::new();
+        testSave.setDaycare(Some(Pokemon::new()));
+
+        let ivResult = testSave.setDaycarePokemonIV_DEF(16);
+
+        assert_eq!(ivResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: DEF IV value is \"16\", which is over max value 15");
+        assert_eq!(testSave.getDaycare().as_ref().unwrap().getIVs().getDEF(), &0);
+    }
+
+    #[test]
+    fn setDaycarePokemonStatus_Empty() {
+        let mut testSave = Save::new();
+
+        let statusResult = testSave.setDaycarePokemonStatus(StatusCondition::Frozen);
+
+        assert_eq!(statusResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: There is no Pokemon in the daycare");
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+    #[test]
+    fn setDaycarePokemonNick_Empty() {
+        let mut testSave = Save::new();
+
+        let nicknameResult = testSave.setDaycarePokemonNick(String::from("SITTER"));
+
+        assert!(nicknameResult.is_err());
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+}
+
+#[cfg(test)]
+mod gameVersionTests {
+    use super::*;
+
+    #[test]
+    fn getGameVersion_Yellow() {
+        let testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
+
+        assert_eq!(testSave.getGameVersion(), &GameVersion::Yellow);
+    }
+
+    #[test]
+    fn getGameVersion_RedBlue() {
+        for filePath in ["./test/POKEMON BLUE.sav", "./test/POKEpi.sav"] {
+            let testSave = Save::load(filePath).unwrap();
+
+            assert_eq!(testSave.getGameVersion(), &GameVersion::RedBlue);
+        }
+    }
+
+    #[test]
+    fn setGameVersion_Correct() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        let versionResult = testSave.setGameVersion(GameVersion::Yellow);
+
+        assert!(versionResult.is_ok());
+        assert_eq!(testSave.getGameVersion(), &GameVersion::Yellow);
+        // Nothing in the save is changed
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+    #[test]
+    fn setGameVersion_IncorrectRegion() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        let versionResult = testSave.setGameVersion(GameVersion::JapaneseYellow);
+
+        assert!(versionResult.is_err());
+        assert_eq!(versionResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Game version \"Yellow (Japanese)\" can't be used for a International save");
+        assert_eq!(testSave.getGameVersion(), &GameVersion::RedBlue);
+    }
+
+}
+
+#[cfg(test)]
+mod pikachuFriendshipTests {
+    use super::*;
+    use super::super::utils::integrityCheck;
+
+    #[test]
+    fn getPikachuFriendship_Correct() {
+        let testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
 
-        // println!("Supposed to be: {0:08b}{:08b}{:08b}{:08b}", save[MONEY_ADDR],save[MONEY_ADDR+1],save[MONEY_ADDR+2]);
-        // println!("Is: {:032b}", moneyInt);
+        assert_eq!(testSave.getPikachuFriendship(), Ok(&116));
+        assert_eq!(testSave.getStarterPikachu(), Ok(0));
+    }
 
-        // return moneyInt
+    #[test]
+    fn getPikachuFriendship_NotYellow() {
+        let testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
 
-        return format!("{:02X}{:02X}{:02X}",save[MONEY_ADDR],save[MONEY_ADDR+1],save[MONEY_ADDR+2])
-        .parse::<u32>()
-        .unwrap();
-        
-    }
+        let friendshipResult = testSave.getPikachuFriendship();
 
-    /// Retrieves the trainer ID
-    fn getTrainerIDFromSave(save: &Vec<u8>) -> u16 {
+        assert!(friendshipResult.is_err());
+        assert_eq!(friendshipResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Pikachu's friendship is only in Yellow, but this save is from Red/Blue");
+    }
 
-        let mut trainerID:u16 = 0b0;
+    #[test]
+    fn setPikachuFriendship_Written() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
 
-        trainerID |= (save[ID_ADDR] as u16) << 8;
-        trainerID |= save[ID_ADDR+1] as u16;
+        testSave.setPikachuFriendship(255).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
 
-        return trainerID;
+        assert_eq!(writtenSave[PIKACHU_FRIENDSHIP_ADDR], 255);
+        assert!(integrityCheck(&writtenSave, &INTERNATIONAL));
     }
 
-    /// Retrieves the players party of Pokemon
-    fn getPartyFromSave(save: &Vec<u8>) -> Vec<Pokemon> {
-        let mut party:  Vec<Pokemon> = Vec::new();
+    #[test]
+    fn setPikachuFriendship_NoPikachu() {
+        // The starter has evolved into Raichu
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
 
-        for creature in 0..save[PARTY_ADDR] as usize {
-            let pkmnAddress: usize = PARTY_ADDR + 0x8 + (creature * 0x2C);
-            let nickAddress: usize = PARTY_ADDR + NICK_OFF + (creature * 0xB);
+        let friendshipResult = testSave.setPikachuFriendship(255);
 
-            // Get current HP
-            let hp = Self::getPokemonHPFromSave(&save,&pkmnAddress);
-            // Nickname Obtaining code
-            let nickname = Self::getPokemonNickFromSave(&save, &nickAddress);
-            // Moves Obtaining code
-            let moves = Self::getPokemonMovesFromSave(&save,&pkmnAddress);
-            // EV Obtaining code
-            let evs: [u16;5] = Self::getPokemonEVsFromSave(&save,&pkmnAddress);
-            // Stat Obtaining Code
-            let stats: [u16;5] = Self::getPokemonStatsFromSave(&save,&pkmnAddress);
-            // IV Obtaining Code
-            let ivs: [u16;5] = Self::getPokemonIVsFromSave(&save,&pkmnAddress);
-            // Original Trainer Obtaining Code
-            let ot = Self::getPokemonOTIDFromSave(&save,&pkmnAddress);
-            let otn = Self::getPokemonOTNameFromSave(&save, &(pkmnAddress+OTN_OFF));
+        assert!(friendshipResult.is_err());
+        assert_eq!(friendshipResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: There is no Pikachu in the party");
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
 
-            party.push(Pokemon::get(    save[pkmnAddress] as i16,
-                                        save[pkmnAddress+0x21] as i8,
-                                        nickname,
-                                        moves,
-                                        ot,
-                                        otn,
-                                        hp, 
-                                        evs, ivs, stats)
-                    );
+    #[test]
+    fn setPikachuFriendship_IncorrectOTID() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
+        testSave.setPartyPokemonOTID(0, 12345).unwrap();
 
-            // println!("Current Pokemon: {:#?}", party[creature]);
-        }
+        let friendshipResult = testSave.setPikachuFriendship(255);
 
-        return party;
+        assert!(friendshipResult.is_err());
+        assert_eq!(friendshipResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: The Pikachu in party slot 0 isn't the players starter, as its Original Trainer ID 12345 doesn't match the players ID 58434");
     }
 
-    /// Retrieves all of the players PC boxes
-    fn getPCBoxesFromSave(save: &Vec<u8>) -> Vec<Vec<Pokemon>>{
-        let mut boxes: Vec<Vec<Pokemon>> = Vec::new();
+    #[test]
+    fn setPikachuFriendship_IncorrectOTN() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
+        testSave.setPartyPokemonOTN(0, String::from("ASH")).unwrap();
 
-        for pcBox in 0..12 as usize {
-            let mut currBox: Vec<Pokemon> = Vec::new();
-            // The boxes first two bytes
-            let currAddr = PC_ADDR + (0x462*pcBox)%0x1A4C + (0x2000*(pcBox/6));
-            let pkmnInBox = save[currAddr] as usize;
-            // println!("Pokemon in box {} at {:X}: {}",pcBox+1,currAddr,pkmnInBox);
-
-            for creature in 0..pkmnInBox {
-                let pkmnAddress = currAddr + PC_PKMN_OFF + (0x21 * creature);
-                let nickAddress = currAddr + PC_NICK_OFF+ (creature*0xB);
-
-                let currSpecies: i16 = save[pkmnAddress] as i16;
-                let hp = Self::getPokemonHPFromSave(&save, &pkmnAddress);
-                let ot = Self::getPokemonOTIDFromSave(&save, &pkmnAddress);
-                let moves = Self::getPokemonMovesFromSave(&save,&pkmnAddress);
-                let nickname = Self::getPokemonNickFromSave(&save, &nickAddress);
-                let evs: [u16;5] = Self::getPokemonEVsFromSave(&save,&pkmnAddress);
-                let ivs: [u16;5] = Self::getPokemonIVsFromSave(&save,&pkmnAddress);
-                let level: i8 = save[pkmnAddress+0x03] as i8;
-                let otn = Self::getPokemonOTNameFromSave(&save, &(currAddr+PC_TRAINER_OFF+(creature*0xB)));
-                // println!("Original Trainer name at: {:X}",currAddr+PC_TRAINER_OFF+(creature*0xB));
-
-                // TODO: Finish this so it uses the box trick to calculate the proper stats.
-                // https://bulbapedia.bulbagarden.net/wiki/Box_trick
-                let stats: [u16;5] = [0,0,0,0,0];
-
-                let currPkmn = Pokemon::get(currSpecies, 
-                                                        level, 
-                                                        nickname, 
-                                                        moves, 
-                                                        ot, 
-                                                        otn,
-                                                        hp, 
-                                                        evs, 
-                                                        ivs, 
-                                                        stats
-                                                    );
-                
-                currBox.push(currPkmn);
+        let friendshipResult = testSave.setPikachuFriendship(255);
 
-            }
+        assert!(friendshipResult.is_err());
+        assert_eq!(friendshipResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: The Pikachu in party slot 0 isn't the players starter, as its Original Trainer \"ASH\" doesn't match the players name \"ERNESTO\"");
+        assert_eq!(testSave.getPikachuFriendship(), Ok(&116));
+    }
 
-            boxes.push(currBox);
-        }
+}
 
-        // println!("{:#?}",boxes);
-        return boxes;
+#[cfg(test)]
+mod japaneseSaveTests {
+    use super::*;
+
+    /// Builds a Japanese save with a Pikachu in the party, and loads it
+    fn testJapaneseSave() -> Save {
+        let mut save = vec![0; SAVE_SIZE];
+        let partyAddr = JAPANESE.partyAddr;
+
+        // "サトシ", with 12,345 money
+        save[NAME_ADDR..NAME_ADDR+4].copy_from_slice(&[0x8A, 0x93, 0x8B, 0x50]);
+        save[JAPANESE.moneyAddr..JAPANESE.moneyAddr+3].copy_from_slice(&[0x01, 0x23, 0x45]);
+
+        // A level 25 Pikachu, nicknamed "ピカチュウ"
+        save[partyAddr..partyAddr+3].copy_from_slice(&[1, 0x54, 0xFF]);
+        save[partyAddr+0x8] = 0x54;
+        save[partyAddr+0x8+0x03] = 25;
+        save[partyAddr+0x8+0x21] = 25;
+        save[partyAddr+JAPANESE.partyOTNOff..partyAddr+JAPANESE.partyOTNOff+4].copy_from_slice(&[0x8A, 0x93, 0x8B, 0x50]);
+        save[partyAddr+JAPANESE.partyNickOff..partyAddr+JAPANESE.partyNickOff+6].copy_from_slice(&[0x41, 0x85, 0x90, 0xAE, 0x82, 0x50]);
+        repairChecksums(&mut save, &JAPANESE);
+
+        return Save::from_bytes(save).unwrap();
     }
 
-    /// Function for retrieving a Pokemons Original Trainers ID
-    fn getPokemonOTIDFromSave(save: &Vec<u8>, currAddr: &usize) -> u16{
-        let mut pokemonOTID:u16 = 0b0;
+    #[test]
+    fn load_International() {
+        let testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
 
-        pokemonOTID |= (save[currAddr+OT_OFF] as u16) << 8;
-        pokemonOTID |= save[currAddr+OT_OFF+1] as u16;
+        assert_eq!(testSave.getGameRegion(), &GameRegion::International);
+    }
 
-        return pokemonOTID;
+    #[test]
+    fn load_Japanese() {
+        let testSave = testJapaneseSave();
+
+        assert_eq!(testSave.getGameRegion(), &GameRegion::Japanese);
+        assert_eq!(testSave.getGameVersion(), &GameVersion::JapaneseRedGreenBlue);
+        assert_eq!(testSave.getTrainerName(), "サトシ");
+        assert_eq!(testSave.getMoney(), &12_345);
+        assert_eq!(testSave.getPCBoxes().len(), 8);
     }
 
-    /// Function for retrieving a Pokemons Original Trainers Name
-    /// 
-    /// **Note**: There is a current bug where extra "garbage data" is added to OT Names.
-    /// This most likely to do with the function textDecode note taking into account control characters.
-    fn getPokemonOTNameFromSave(save: &Vec<u8>, currAddr: &usize) -> String {
-        let mut encodedName: [i16;11] = [0;11];
+    #[test]
+    fn load_JapaneseParty() {
+        let testSave = testJapaneseSave();
+        let pikachu = &testSave.getParty()[0];
+
+        assert_eq!(pikachu.getSpecies().getName(), "Pikachu");
+        assert_eq!(pikachu.getLevel(), &25);
+        assert_eq!(pikachu.getNickname(), "ピカチュウ");
+        assert_eq!(pikachu.getOTN(), "サトシ");
+    }
 
-        for char in 0..11 {
-            encodedName[char] = format!("{}",save[currAddr+char]).parse::<i16>().unwrap();
-        }
+    #[test]
+    fn hallOfFame_Japanese() {
+        let mut save = vec![0; SAVE_SIZE];
+        let entryAddr = JAPANESE.hofEntryAddr(0, 0);
 
-        return textDecode(&encodedName);
-    }
+        // A team of just a level 25 Pikachu, nicknamed "ピカチュウ"
+        save[JAPANESE.hofCountAddr] = 1;
+        save[entryAddr..entryAddr+2].copy_from_slice(&[0x54, 25]);
+        save[entryAddr+2..entryAddr+8].copy_from_slice(&[0x41, 0x85, 0x90, 0xAE, 0x82, 0x50]);
+        save[JAPANESE.hofEntryAddr(0, 1)] = 0xFF;
 
-    /// Function for retrieving the Pokemons current Health Points
-    fn getPokemonHPFromSave(save: &Vec<u8>, currAddr: &usize) -> i16{
+        let hallOfFame = Save::getHallOfFameFromSave(&save, &JAPANESE);
 
-        let mut pokemonHP:i16 = 0x0;
+        assert_eq!(hallOfFame.getRecords()[0].len(), 1);
+        assert_eq!(hallOfFame.getRecords()[0][0].getNickname(), "ピカチュウ");
 
-        pokemonHP |=  (save[currAddr+HP_OFF] as i16) << 8;
-        pokemonHP |=  save[currAddr+HP_OFF + 1] as i16;
+        let mut writtenSave = vec![0; SAVE_SIZE];
+        Save::writeHallOfFameToSave(&mut writtenSave, &JAPANESE, &hallOfFame).unwrap();
 
-        return  pokemonHP;
+        assert_eq!(writtenSave[JAPANESE.hofAddr..JAPANESE.hofAddr+JAPANESE.hofRecordSize], save[JAPANESE.hofAddr..JAPANESE.hofAddr+JAPANESE.hofRecordSize]);
     }
 
-    /// Function for retrieving a Pokemons Nickname.
-    /// 
-    /// **Note**: This function will automatically decode it into a String
-    fn getPokemonNickFromSave(save: &Vec<u8>,currAddr: &usize) -> String {
-        let mut encodedNick: [i16; 11]= [0; 11];
-            for num in 0..11 {
-                encodedNick[num] = format!("{}",save[currAddr+num]).parse::<i16>().unwrap();
-            }
-        return textDecode(&encodedNick);
-    }
+    #[test]
+    fn hallOfFame_JapaneseNotEditable() {
+        let mut testSave = testJapaneseSave();
 
-    /// Function for retrieving a Pokemons base stats
-    fn getPokemonStatsFromSave(save: &Vec<u8>,currAddr: &usize) -> [u16;5] {
-        let mut stats: [u16;5] = [0x0; 5];
-        
-        for stat in 0..5 {
-            let currAddr = currAddr+STAT_OFF+(stat*2);
-            stats[stat] |= (save[currAddr] as u16) << 8;
-            stats[stat] |= save[currAddr + 1] as u16;
-        }
-        
-        return stats;
+        let clearResult = testSave.clearHallOfFame();
+        let levelResult = testSave.setHallOfFameLevel(0, 0, 50);
+
+        assert_eq!(clearResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Editing the Hall of Fame isn't supported for Japanese saves, as the layout of its entries hasn't been confirmed");
+        assert!(levelResult.is_err());
+        assert!(testSave.getDirtyRegions().is_empty());
     }
 
-    /// Function for retrieving a Pokemons Effort Values
-    fn getPokemonEVsFromSave(save: &Vec<u8>,currAddr: &usize) -> [u16;5] {
-        let mut evs: [u16;5] = [0x0; 5];
+    #[test]
+    fn to_bytes_UnencodableName() {
+        let mut testSave = testJapaneseSave();
 
-        for stat in 0..5 {
-            let currAddr = currAddr+EV_OFF+(stat*2);
-            evs[stat] |= (save[currAddr] as u16) << 8;
-            evs[stat] |= save[currAddr + 1] as u16;
-        }
-        
-        return evs;
+        // A Pokemon with a name that can only be written in international saves
+        testSave.setDaycare(Some(Pokemon::new()));
+
+        let bytesResult = testSave.to_bytes();
+
+        assert!(bytesResult.is_err());
+        assert_eq!(bytesResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Character \"B\" in \"Bobsaur\" can't be used in Japanese Gen 1 text");
     }
 
-    /// Function for retrieving data about Pokemons moves.
-    fn getPokemonMovesFromSave(save: &Vec<u8>, currAddr: &usize) -> Vec<Move>{
-    let mut returnVec: Vec<Move> = Vec::new();
-    let moveAddr = currAddr + MOVE_OFF;
+    #[test]
+    fn setTrainerName_Japanese() {
+        let mut testSave = testJapaneseSave();
 
-    for moves in 0..4 {
-        let moveIndex = save[moveAddr+moves] as u16;
+        testSave.setTrainerName(String::from("レッド")).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
 
-        // As per the Wikiw (https://m.bulbapedia.bulbagarden.net/wiki/Pok%C3%A9mon_data_structure_(Generation_I)#PP), PP values are determined by:
-        // The current PP-Up being the first two bits of the move
-        // The current PP value being the remaining 6 bits
-        let ppValues = save[currAddr+PP_OFF+moves];
+        // Only the 6 bytes of the name are written
+        assert_eq!(writtenSave[NAME_ADDR..NAME_ADDR+7], [0xA7, 0xAC, 0x13, 0x50, 0x50, 0x50, 0x00]);
+        assert_eq!(detectLayout(&writtenSave).unwrap().region, GameRegion::Japanese);
+    }
 
-        let PP: u16 = ((ppValues << 2) >> 2) as u16 ;
-        let PPUp: u8 = ppValues >> 6;
+    #[test]
+    fn setTrainerName_JapaneseIncorrectLength() {
+        let mut testSave = testJapaneseSave();
 
-        if moveIndex == 0 {
-            returnVec.push(Move::empty());
-        } else {
-            returnVec.push(Move::get(moveIndex, PP, PPUp).unwrap());
-        }
+        let nameResult = testSave.setTrainerName(String::from("サトシサトシ"));
+
+        assert!(nameResult.is_err());
+        assert_eq!(nameResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Name \"サトシサトシ\" is over 5 characters.");
     }
 
-    return returnVec;
-}
+    #[test]
+    fn setPartyPokemonNick_JapaneseIncorrectChar() {
+        let mut testSave = testJapaneseSave();
 
-    /// Function for retrieving a Pokemons Individual Values 
-    /// Also known as [Determinant Values](https://m.bulbapedia.bulbagarden.net/wiki/Individual_values)
-    /// 
-    /// **NOTE**: This function does not have a bit manip test as the original implementation
-    /// was faulty. 
-    fn getPokemonIVsFromSave(save: &Vec<u8>,currAddr: &usize) -> [u16;5]{
+        let nicknameResult = testSave.setPartyPokemonNick(0, String::from("SPARKY"));
 
-        let atk = (save[currAddr+IV_OFF] >> 4) as u16;
-        let def = ((save[currAddr+IV_OFF] << 4) >> 4) as u16;
-        let spd = (save[currAddr+IV_OFF+1] >> 4) as u16;
-        let spc = ((save[currAddr+IV_OFF+1] << 4) >> 4) as u16;
+        assert!(nicknameResult.is_err());
+        assert_eq!(nicknameResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Character \"S\" in \"SPARKY\" can't be used in Japanese Gen 1 text");
+        assert_eq!(testSave.getParty()[0].getNickname(), "ピカチュウ");
+    }
 
-       
-        // For the HPIVs we have to get the last bits of all four stats
-        let mut hp = 0;
-        hp |= (atk & 1) << 3 | (def & 1) << 2 | (spd & 1) << 1 | (spc & 1);
+    #[test]
+    fn setPartyPokemonNick_KanaInInternational() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW.sav").unwrap();
 
-        let ivs: [u16;5] = [
-                                hp,
-                                atk,
-                                def,
-                                spd,
-                                spc
-                            ];
-        
-        return ivs;
+        let nicknameResult = testSave.setPartyPokemonNick(0, String::from("ピカ"));
+
+        assert!(nicknameResult.is_err());
+        assert_eq!(nicknameResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Character \"ピ\" in \"ピカ\" can't be used in Gen 1 text");
     }
 
 }
 
 #[cfg(test)]
-mod fileLoadingTests {
+mod expTests {
     use super::*;
 
     #[test]
-    fn load_InvalidFile() {
-        let fileName = "./test/POKEMON CRYSTAL.sav";
-        let saveFile = Save::load(fileName);
+    fn load_ExpMatchesLevel() {
+        let testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
 
-        assert!(saveFile.is_err());
-        assert_eq!(saveFile.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: File does not seem to be a Gen 1 Save File");
-        
+        for pokemon in testSave.getParty().iter().chain(testSave.getPCBoxes().iter().flatten()) {
+            assert!(pokemon.checkExp().is_ok());
+        }
     }
 
     #[test]
-    fn load_ValidFile() {
-        let fileName = "./test/POKEMON BLUE.sav";
-        let saveFile = Save::load(fileName);
+    fn load_ExpMismatchFlagged() {
+        // This save has had its Pokemon's levels edited, without changing their experience
+        let testSave = Save::load("./test/Pokeblue.sav").unwrap();
 
-        assert!(saveFile.is_ok());
+        assert_eq!(testSave.getParty()[3].getExp(), &156_250);
+        assert_eq!(testSave.getParty()[3].checkExp().unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Zapdos is level 91, but has 156250 EXP, which is level 50");
     }
 
     #[test]
-    fn load_NonexistentFile() {
-        let fileName = "./test/Nonexistent File.sav";
-        let saveFile = Save::load(fileName);
+    fn setPartyPokemonLevel_WritesExp() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
 
-        assert!(saveFile.is_err());
-        assert_eq!(saveFile.unwrap_err(), format!("\u{1b}[0;31mError\u{1b}[0m: Save \"{}\" does not exist",fileName));
-        
-    }
+        // Pidgey is Medium Slow
+        testSave.setPartyPokemonLevel(0, 10).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
 
+        assert_eq!(Save::getPokemonExpFromSave(&writtenSave, &(PARTY_ADDR + 0x8)), 560);
+        assert!(Save::getPartyFromSave(&writtenSave, &INTERNATIONAL)[0].checkExp().is_ok());
+    }
 }
 
 #[cfg(test)]
-mod trainerTests {
+mod typeTests {
     use super::*;
+    use super::super::creatureData::pokemonType::Type;
 
     #[test]
-    fn setTrainerName_Correct() {
-        // This is the save we'll be testing with
-        let mut currSave = Save::new();
+    fn load_TypesMatchSpecies() {
+        let testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
 
-        // This is the trainer name we'll be setting
-        let newName = String::from("Brock");
-
-        // Now we change the name
-        let nameChangeResult = currSave.setTrainerName(newName);
-
-        // Once Gotten, we expect it to be Ok() and not Err()
-        assert!(nameChangeResult.is_ok());
-        // And we expect the unwrapped version of the name to be true
-        assert_eq!(nameChangeResult.unwrap(), true);
+        for pokemon in testSave.getParty().iter().chain(testSave.getPCBoxes().iter().flatten()) {
+            assert!(pokemon.checkTypes().is_ok());
+        }
     }
 
     #[test]
-    fn setTrainerName_Incorrect() {
-        // This is the save we'll be testing with
-        let mut currSave = Save::new();
-
-        // This is the trainer name we'll be setting.
-        // The maximum length for a name in gen 1 is 7 chars.
-        // Thus we will be testing with more than 7
-        let newName = String::from("Professor Oak");
-
-        // Now we change the name
-        let nameChangeResult = currSave.setTrainerName(newName);
-
-        // Once Gotten, we expect it to be Ok() and not Err()
-        assert!(nameChangeResult.is_err());
-        // And we expect the unwrapped version of the name to be true
-        assert_eq!(nameChangeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Name \"Professor Oak\" is over 7 characters.");
+    fn load_TypesAndCatchRate() {
+        let testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
+        let pikachu = &testSave.getParty()[0];
+
+        assert_eq!(pikachu.getTypes(), &[0x17, 0x17]);
+        assert_eq!(pikachu.getTyping().unwrap(), [Type::Electric, Type::Electric]);
+        // The starter Pikachu in Yellow is given a different catch rate to wild ones
+        assert_eq!(pikachu.getCatchRate(), &163);
+        assert_eq!(pikachu.checkCatchRate().unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: PIKACHU has a catch rate of 163, but Pikachu has a catch rate of 190");
     }
 
     #[test]
-    fn setMoney_Correct() {
-        let mut testSave = Save::new();
-
-        let newMoneyAmount:u32 = 1000;
+    fn checkTypes_Mismatch() {
+        let mut save = fs::read("./test/POKEMON YELLOW 2.sav").unwrap();
+        // Make Pikachu Fire type
+        save[PARTY_ADDR + 0x8 + TYPE_OFF] = 0x14;
 
-        let moneyChangeResult = testSave.setMoney(newMoneyAmount);
+        let pikachu = &Save::getPartyFromSave(&save, &INTERNATIONAL)[0];
 
-        assert!(moneyChangeResult.is_ok());
-        assert_eq!(moneyChangeResult.unwrap(), true);
+        assert_eq!(pikachu.checkTypes().unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: PIKACHU is Fire/Electric, but Pikachu is Electric/Electric");
+        assert_eq!(pikachu.getTyping().unwrap(), [Type::Fire, Type::Electric]);
     }
 
     #[test]
-    fn setMoney_Incorrect() {
-        let mut testSave = Save::new();
+    fn getTyping_GlitchType() {
+        let mut save = fs::read("./test/POKEMON YELLOW 2.sav").unwrap();
+        // 0x06 is the unused Bird type
+        save[PARTY_ADDR + 0x8 + TYPE_OFF + 1] = 0x06;
 
-        let newMoneyAmount:u32 = 1_000_000;
-
-        let moneyChangeResult = testSave.setMoney(newMoneyAmount);
+        let pikachu = &Save::getPartyFromSave(&save, &INTERNATIONAL)[0];
 
-        assert!(moneyChangeResult.is_err());
-        assert_eq!(moneyChangeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Amount \"1000000\" is over allowed maximum 999,999.") 
+        assert_eq!(pikachu.getTyping().unwrap_err(), "Gen 1 Type with ID 0x06 not found.");
+        assert_eq!(pikachu.checkTypes().unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: PIKACHU is Electric/0x06, but Pikachu is Electric/Electric");
     }
 
     #[test]
-    fn setID_Correct() {
-        let mut testSave = Save::new();
+    fn to_bytes_WritesTypesAndCatchRate() {
+        let original = fs::read("./test/POKEMON BLUE.sav").unwrap();
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+        let dataRange = PARTY_ADDR + 0x8 + TYPE_OFF..PARTY_ADDR + 0x8 + CATCH_RATE_OFF + 1;
+
+        // Editing the party makes every Pokemon in it be written back
+        testSave.setPartyPokemonNick(0, String::from("BIRDY")).unwrap();
+        assert!(testSave.getDirtyRegions().contains(&Region::Party));
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        // Pidgey is Normal/Flying with a catch rate of 255
+        assert_eq!(writtenSave[dataRange.clone()], [0x00, 0x02, 255]);
+        assert_eq!(writtenSave[dataRange.clone()], original[dataRange]);
+    }
 
-        let newID:u16 = 12345;
+    #[test]
+    fn to_bytes_KeepsCatchRateDifferentFromSpecies() {
+        let mut testSave = Save::load("./test/POKEMON YELLOW 2.sav").unwrap();
+        let catchRateAddr = PARTY_ADDR + 0x8 + CATCH_RATE_OFF;
 
-        testSave.setID(newID);
+        testSave.setPartyPokemonNick(0, String::from("SPARKY")).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
 
-        assert_eq!(testSave.getTrainerID(), &newID);
+        // Yellow's starter Pikachu is given a catch rate of 163, instead of the species' 190
+        assert_eq!(writtenSave[catchRateAddr], 163);
+        assert_eq!(Save::getPartyFromSave(&writtenSave, &INTERNATIONAL)[0].getCatchRate(), &163);
+        assert_eq!(writtenSave[PARTY_ADDR + 0x8 + TYPE_OFF..catchRateAddr], [0x17, 0x17]);
     }
-
 }
 
 #[cfg(test)]
@@ -854,6 +3775,74 @@ mod basicPartyPokemonSetterTests {
         assert_eq!(levelChangeResult.unwrap(), true); 
     }
 
+    #[test]
+    fn setPartyPokemonExp_Written() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        testSave.setPartyPokemonExp(1, 1000).unwrap();
+        assert!(testSave.getDirtyRegions().contains(&Region::Party));
+        let writtenSave = testSave.to_bytes().unwrap();
+        let writtenPokemon = &Save::getPartyFromSave(&writtenSave, &INTERNATIONAL)[1];
+
+        assert_eq!(writtenSave[PARTY_ADDR + 0x8 + 0x2C + EXP_OFF..PARTY_ADDR + 0x8 + 0x2C + EXP_OFF + 3], [0x00, 0x03, 0xE8]);
+        assert_eq!(writtenPokemon.getExp(), &1000);
+        assert!(writtenPokemon.checkExp().is_ok());
+        assert!(writtenPokemon.hasCalculatedStats());
+    }
+
+    #[test]
+    fn setPartyPokemonExp_IncorrectIndex() {
+        let mut testSave = Save::new();
+
+        let expResult = testSave.setPartyPokemonExp(1, 1000);
+
+        assert_eq!(expResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: There is no Pokemon in party slot 1");
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
+    #[test]
+    fn setPartyPokemonStatus_Written() {
+        let mut testSave = Save::load("./test/POKEMON BLUE.sav").unwrap();
+
+        testSave.setPartyPokemonStatus(1, StatusCondition::Paralyzed).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        assert_eq!(writtenSave[PARTY_ADDR + 0x8 + 0x2C + STATUS_OFF], 0x40);
+        assert_eq!(Save::getPartyFromSave(&writtenSave, &INTERNATIONAL)[1].getStatus(), StatusCondition::Paralyzed);
+        assert_eq!(Save::getPartyFromSave(&writtenSave, &INTERNATIONAL)[0].getStatus(), StatusCondition::Healthy);
+    }
+
+    #[test]
+    fn setPartyPokemonStatus_KeepsOtherStatusBytes() {
+        let mut editedSave = fs::read("./test/POKEMON BLUE.sav").unwrap();
+
+        // Poisoned and paralyzed at once, which the game never sets by itself
+        editedSave[PARTY_ADDR + 0x8 + STATUS_OFF] = 0x48;
+        repairChecksums(&mut editedSave, &INTERNATIONAL);
+
+        let mut testSave = Save::from_bytes(editedSave).unwrap();
+
+        assert_eq!(testSave.getParty()[0].getStatus(), StatusCondition::Poisoned);
+        assert_eq!(testSave.getParty()[0].checkStatus().unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: PIDGEY has the status byte 0x48, which is more than one status");
+
+        testSave.setPartyPokemonStatus(1, StatusCondition::Burned).unwrap();
+        let writtenSave = testSave.to_bytes().unwrap();
+
+        // The unedited Pokemon keeps both of its status bits
+        assert_eq!(writtenSave[PARTY_ADDR + 0x8 + STATUS_OFF], 0x48);
+        assert_eq!(writtenSave[PARTY_ADDR + 0x8 + 0x2C + STATUS_OFF], 0x10);
+    }
+
+    #[test]
+    fn setPartyPokemonStatus_IncorrectIndex() {
+        let mut testSave = Save::new();
+
+        let statusResult = testSave.setPartyPokemonStatus(1, StatusCondition::Frozen);
+
+        assert_eq!(statusResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: There is no Pokemon in party slot 1");
+        assert!(testSave.getDirtyRegions().is_empty());
+    }
+
     #[test]
     fn setPartyPokemonLevel_IncorrectIndex() {
         let mut testSave = Save::new();
@@ -1095,15 +4084,15 @@ mod IVPartyPokemonSetterTests {
     use super::*;
 
     #[test]
-    fn setPartyPokemonIV_HP_Correct() {
+    fn setPartyPokemonIV_HP_Derived() {
         let mut testSave = Save::new();
 
         let newHP:u16 = 15;
 
         let changeHPResult = testSave.setPartyPokemonIV_HP(0, newHP);
 
-        assert!(changeHPResult.is_ok());
-        assert_eq!(changeHPResult.unwrap(), true);
+        assert!(changeHPResult.is_err());
+        assert_eq!(changeHPResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: HP IV can't be set to \"15\", as it is derived from the ATK, DEF, SPD and SPC IVs");
     }
 
     #[test]
@@ -1297,7 +4286,7 @@ mod fileLoadingBitManipulationTests {
             let testSave = fs::read(save).unwrap();
 
             // Get the ID with bit manipulation
-            let readID = Save::getTrainerIDFromSave(&testSave);
+            let readID = Save::getTrainerIDFromSave(&testSave, &INTERNATIONAL);
             // Get the expected ID with the simpled String-radix method
             let expectedlID = u16::from_str_radix(&format!("{:02X}{:02X}",testSave[ID_ADDR],testSave[ID_ADDR+1]), 16).unwrap();
 
@@ -1457,4 +4446,4 @@ mod fileLoadingBitManipulationTests {
 
     }
 
-}
\ No newline at end of file
+}
@@ -1,175 +1,773 @@
-use super::addresses::{CHECKSUM_END_ADDR, CHECKSUM_RESULT, CHECKSUM_START_ADDR};
+use super::addresses::*;
 use std::num::Wrapping;
 
+pub mod bcd;
+pub mod container;
+
+/// The character that ends a string of text.
+/// Names shorter than their field are terminated, and then padded out, with it.
+pub const TEXT_TERMINATOR: i16 = 0x50;
+
 /// Decodes text, as text in most games uses character encoding
+/// 
+/// Decoding stops at the terminator, and any character that isn't in the
+/// Gen 1 character set (such as control characters) is decoded as a space.
+/// The last byte is left for the terminator, so at most 10 characters are decoded.
+/// The character set can be found [here](https://bulbapedia.bulbagarden.net/wiki/Character_encoding_(Generation_I))
 pub fn textDecode(encoded: &[i16; 11]) -> String{
-    let mut encodedText: Vec<u8> = Vec::new();
-
-    for char in encoded {
-        match char {
-            0x80 => encodedText.push(b'A'),
-            0x81 => encodedText.push(b'B'),
-            0x82 => encodedText.push(b'C'),
-            0x83 => encodedText.push(b'D'),
-            0x84 => encodedText.push(b'E'),
-            0x85 => encodedText.push(b'F'),
-            0x86 => encodedText.push(b'G'),
-            0x87 => encodedText.push(b'H'),
-            0x88 => encodedText.push(b'I'),
-            0x89 => encodedText.push(b'J'),
-            0x8A => encodedText.push(b'K'),
-            0x8B => encodedText.push(b'L'),
-            0x8C => encodedText.push(b'M'),
-            0x8D => encodedText.push(b'N'),
-            0x8E => encodedText.push(b'O'),
-            0x8F => encodedText.push(b'P'),
-            0x90 => encodedText.push(b'Q'),
-            0x91 => encodedText.push(b'R'),
-            0x92 => encodedText.push(b'S'),
-            0x93 => encodedText.push(b'T'),
-            0x94 => encodedText.push(b'U'),
-            0x95 => encodedText.push(b'V'),
-            0x96 => encodedText.push(b'W'),
-            0x97 => encodedText.push(b'X'),
-            0x98 => encodedText.push(b'Y'),
-            0x99 => encodedText.push(b'Z'),
-            0x9A => encodedText.push(b'('),
-            0x9B => encodedText.push(b')'),
-            0x9C => encodedText.push(b':'),
-            0x9D => encodedText.push(b';'),
-            0x9E => encodedText.push(b'['),
-            0x9F => encodedText.push(b']'),
-            0xA0 => encodedText.push(b'a'),
-            0xA1 => encodedText.push(b'b'),
-            0xA2 => encodedText.push(b'c'),
-            0xA3 => encodedText.push(b'd'),
-            0xA4 => encodedText.push(b'e'),
-            0xA5 => encodedText.push(b'f'),
-            0xA6 => encodedText.push(b'g'),
-            0xA7 => encodedText.push(b'h'),
-            0xA8 => encodedText.push(b'i'),
-            0xA9 => encodedText.push(b'j'),
-            0xAA => encodedText.push(b'k'),
-            0xAB => encodedText.push(b'l'),
-            0xAC => encodedText.push(b'm'),
-            0xAD => encodedText.push(b'n'),
-            0xAE => encodedText.push(b'o'),
-            0xAF => encodedText.push(b'p'),
-            0xB0 => encodedText.push(b'q'),
-            0xB1 => encodedText.push(b'r'),
-            0xB2 => encodedText.push(b's'),
-            0xB3 => encodedText.push(b't'),
-            0xB4 => encodedText.push(b'u'),
-            0xB5 => encodedText.push(b'v'),
-            0xB6 => encodedText.push(b'w'),
-            0xB7 => encodedText.push(b'x'),
-            0xB8 => encodedText.push(b'y'),
-            0xB9 => encodedText.push(b'z'),
-            0xBA => encodedText.extend_from_slice(&[b'\xC3', b'\xA9']),
-            0x50 => encodedText.push(b' '),
-            _   => encodedText.push(b' ') 
-        }; 
-    }
-
-    let decodedText = String::from_utf8(encodedText).unwrap();
+    let mut decodedText = String::new();
+
+    for char in encoded.iter().take(10) {
+        let decodedChar = match char {
+            0x50 => break,
+            0x7F => " ",
+            0x80 => "A",
+            0x81 => "B",
+            0x82 => "C",
+            0x83 => "D",
+            0x84 => "E",
+            0x85 => "F",
+            0x86 => "G",
+            0x87 => "H",
+            0x88 => "I",
+            0x89 => "J",
+            0x8A => "K",
+            0x8B => "L",
+            0x8C => "M",
+            0x8D => "N",
+            0x8E => "O",
+            0x8F => "P",
+            0x90 => "Q",
+            0x91 => "R",
+            0x92 => "S",
+            0x93 => "T",
+            0x94 => "U",
+            0x95 => "V",
+            0x96 => "W",
+            0x97 => "X",
+            0x98 => "Y",
+            0x99 => "Z",
+            0x9A => "(",
+            0x9B => ")",
+            0x9C => ":",
+            0x9D => ";",
+            0x9E => "[",
+            0x9F => "]",
+            0xA0 => "a",
+            0xA1 => "b",
+            0xA2 => "c",
+            0xA3 => "d",
+            0xA4 => "e",
+            0xA5 => "f",
+            0xA6 => "g",
+            0xA7 => "h",
+            0xA8 => "i",
+            0xA9 => "j",
+            0xAA => "k",
+            0xAB => "l",
+            0xAC => "m",
+            0xAD => "n",
+            0xAE => "o",
+            0xAF => "p",
+            0xB0 => "q",
+            0xB1 => "r",
+            0xB2 => "s",
+            0xB3 => "t",
+            0xB4 => "u",
+            0xB5 => "v",
+            0xB6 => "w",
+            0xB7 => "x",
+            0xB8 => "y",
+            0xB9 => "z",
+            0xBA => "é",
+            0xBB => "'d",
+            0xBC => "'l",
+            0xBD => "'s",
+            0xBE => "'t",
+            0xBF => "'v",
+            0xE0 => "'",
+            0xE1 => "ᴾᴷ",
+            0xE2 => "ᴹᴺ",
+            0xE3 => "-",
+            0xE4 => "'r",
+            0xE5 => "'m",
+            0xE6 => "?",
+            0xE7 => "!",
+            0xE8 => ".",
+            0xEF => "♂",
+            0xF0 => "¥",
+            0xF1 => "×",
+            0xF3 => "/",
+            0xF4 => ",",
+            0xF5 => "♀",
+            0xF6 => "0",
+            0xF7 => "1",
+            0xF8 => "2",
+            0xF9 => "3",
+            0xFA => "4",
+            0xFB => "5",
+            0xFC => "6",
+            0xFD => "7",
+            0xFE => "8",
+            0xFF => "9",
+            _    => " "
+        };
+
+        decodedText.push_str(decodedChar);
+    }
 
     return decodedText;
 }
 
-#[allow(dead_code)]
 /// Encodes text into the character encoding used by Gen 1
-pub fn textEncode(decoded: &String) -> [i16; 11]{
-    let mut encoded: [i16; 11] = [0; 11];
-
-    for index in 0..11 {
-        let currChar = decoded.chars().nth(index).unwrap();
-        encoded[index] = match currChar {
-            'A' => 0x80,
-            'B' => 0x81,
-            'C' => 0x82,
-            'D' => 0x83,
-            'E' => 0x84,
-            'F' => 0x85,
-            'G' => 0x86,
-            'H' => 0x87,
-            'I' => 0x88,
-            'J' => 0x89,
-            'K' => 0x8A,
-            'L' => 0x8B,
-            'M' => 0x8C,
-            'N' => 0x8D,
-            'O' => 0x8E,
-            'P' => 0x8F,
-            'Q' => 0x90,
-            'R' => 0x91,
-            'S' => 0x92,
-            'T' => 0x93,
-            'U' => 0x94,
-            'V' => 0x95,
-            'W' => 0x96,
-            'X' => 0x97,
-            'Y' => 0x98,
-            'Z' => 0x99,
-            '(' => 0x9A,
-            ')' => 0x9B,
-            ':' => 0x9C,
-            ';' => 0x9D,
-            '[' => 0x9E,
-            ']' => 0x9F,
-            'a' => 0xA0,
-            'b' => 0xA1,
-            'c' => 0xA2,
-            'd' => 0xA3,
-            'e' => 0xA4,
-            'f' => 0xA5,
-            'g' => 0xA6,
-            'h' => 0xA7,
-            'i' => 0xA8,
-            'j' => 0xA9,
-            'k' => 0xAA,
-            'l' => 0xAB,
-            'm' => 0xAC,
-            'n' => 0xAD,
-            'o' => 0xAE,
-            'p' => 0xAF,
-            'q' => 0xB0,
-            'r' => 0xB1,
-            's' => 0xB2,
-            't' => 0xB3,
-            'u' => 0xB4,
-            'v' => 0xB5,
-            'w' => 0xB6,
-            'x' => 0xB7,
-            'y' => 0xB8,
-            'z' => 0xB9,
-            'é' => 0xBA,
-            ' ' => 0x50,
-            _   => 0x50 
+/// 
+/// The text is terminated, and the rest of the field is filled with the terminator.
+/// The PK and MN characters are written as "ᴾᴷ" and "ᴹᴺ", as they are when decoded,
+/// and an apostrophe before d, l, s, t, v, r or m is encoded as the single character the game has for it.
+pub fn textEncode(decoded: &String) -> Result<[i16; 11], String>{
+    let mut encoded: [i16; 11] = [TEXT_TERMINATOR; 11];
+    let mut chars = decoded.chars().peekable();
+    let mut index = 0;
+
+    while let Some(currChar) = chars.next() {
+
+        // PK, MN and the contractions are each a single character in Gen 1
+        let nextChar = chars.peek().copied();
+        let encodedChar = match (currChar, nextChar) {
+            ('ᴾ', Some('ᴷ')) => { chars.next(); 0xE1 },
+            ('ᴹ', Some('ᴺ')) => { chars.next(); 0xE2 },
+            ('\'', Some('d')) => { chars.next(); 0xBB },
+            ('\'', Some('l')) => { chars.next(); 0xBC },
+            ('\'', Some('s')) => { chars.next(); 0xBD },
+            ('\'', Some('t')) => { chars.next(); 0xBE },
+            ('\'', Some('v')) => { chars.next(); 0xBF },
+            ('\'', Some('r')) => { chars.next(); 0xE4 },
+            ('\'', Some('m')) => { chars.next(); 0xE5 },
+            _                 => match encodeChar(currChar) {
+                Some(encodedChar)   => encodedChar,
+                None                => return Err(formatError(format!("Character \"{}\" in \"{}\" can't be used in Gen 1 text", currChar, decoded)))
+            }
+        };
+
+        // The last byte is always left for the terminator
+        if index >= 10 {
+            return Err(formatError(format!("Text \"{}\" is over 10 characters.", decoded)));
         }
+
+        encoded[index] = encodedChar;
+        index += 1;
     }
 
-    return encoded;
+    return Ok(encoded);
 }
 
-pub fn integrityCheck(saveFile: &Vec<u8>) -> bool {
-    // We will be using the "easy" way, as shown in the
-    // Checksum section of the bulbapedia https://m.bulbapedia.bulbagarden.net/wiki/Save_data_structure_(Generation_I)#Checksum
+/// Encodes a single character, returning `None` if it isn't in the Gen 1 character set
+fn encodeChar(decoded: char) -> Option<i16> {
+    let encoded = match decoded {
+        ' ' => 0x7F,
+        'A' => 0x80,
+        'B' => 0x81,
+        'C' => 0x82,
+        'D' => 0x83,
+        'E' => 0x84,
+        'F' => 0x85,
+        'G' => 0x86,
+        'H' => 0x87,
+        'I' => 0x88,
+        'J' => 0x89,
+        'K' => 0x8A,
+        'L' => 0x8B,
+        'M' => 0x8C,
+        'N' => 0x8D,
+        'O' => 0x8E,
+        'P' => 0x8F,
+        'Q' => 0x90,
+        'R' => 0x91,
+        'S' => 0x92,
+        'T' => 0x93,
+        'U' => 0x94,
+        'V' => 0x95,
+        'W' => 0x96,
+        'X' => 0x97,
+        'Y' => 0x98,
+        'Z' => 0x99,
+        '(' => 0x9A,
+        ')' => 0x9B,
+        ':' => 0x9C,
+        ';' => 0x9D,
+        '[' => 0x9E,
+        ']' => 0x9F,
+        'a' => 0xA0,
+        'b' => 0xA1,
+        'c' => 0xA2,
+        'd' => 0xA3,
+        'e' => 0xA4,
+        'f' => 0xA5,
+        'g' => 0xA6,
+        'h' => 0xA7,
+        'i' => 0xA8,
+        'j' => 0xA9,
+        'k' => 0xAA,
+        'l' => 0xAB,
+        'm' => 0xAC,
+        'n' => 0xAD,
+        'o' => 0xAE,
+        'p' => 0xAF,
+        'q' => 0xB0,
+        'r' => 0xB1,
+        's' => 0xB2,
+        't' => 0xB3,
+        'u' => 0xB4,
+        'v' => 0xB5,
+        'w' => 0xB6,
+        'x' => 0xB7,
+        'y' => 0xB8,
+        'z' => 0xB9,
+        'é' => 0xBA,
+        '\'' => 0xE0,
+        '-' => 0xE3,
+        '?' => 0xE6,
+        '!' => 0xE7,
+        '.' => 0xE8,
+        '♂' => 0xEF,
+        '¥' => 0xF0,
+        '×' => 0xF1,
+        '/' => 0xF3,
+        ',' => 0xF4,
+        '♀' => 0xF5,
+        '0' => 0xF6,
+        '1' => 0xF7,
+        '2' => 0xF8,
+        '3' => 0xF9,
+        '4' => 0xFA,
+        '5' => 0xFB,
+        '6' => 0xFC,
+        '7' => 0xFD,
+        '8' => 0xFE,
+        '9' => 0xFF,
+        _   => return None
+    };
+
+    return Some(encoded);
+}
+
+/// Decodes text from the Japanese games, which use a kana character set
+/// 
+/// This works the same as `textDecode`, but Japanese names are only 6 bytes,
+/// so at most 5 characters are decoded.
+/// The character set can be found [here](https://bulbapedia.bulbagarden.net/wiki/Character_encoding_(Generation_I)#Japanese)
+pub fn kanaDecode(encoded: &[i16; 11]) -> String{
+    let mut decodedText = String::new();
+
+    for char in encoded.iter().take(5) {
+        let decodedChar = match char {
+            0x50 => break,
+            0x05 => "ガ",
+            0x06 => "ギ",
+            0x07 => "グ",
+            0x08 => "ゲ",
+            0x09 => "ゴ",
+            0x0A => "ザ",
+            0x0B => "ジ",
+            0x0C => "ズ",
+            0x0D => "ゼ",
+            0x0E => "ゾ",
+            0x0F => "ダ",
+            0x10 => "ヂ",
+            0x11 => "ヅ",
+            0x12 => "デ",
+            0x13 => "ド",
+            0x19 => "バ",
+            0x1A => "ビ",
+            0x1B => "ブ",
+            0x1C => "ボ",
+            0x26 => "が",
+            0x27 => "ぎ",
+            0x28 => "ぐ",
+            0x29 => "げ",
+            0x2A => "ご",
+            0x2B => "ざ",
+            0x2C => "じ",
+            0x2D => "ず",
+            0x2E => "ぜ",
+            0x2F => "ぞ",
+            0x30 => "だ",
+            0x31 => "ぢ",
+            0x32 => "づ",
+            0x33 => "で",
+            0x34 => "ど",
+            0x3A => "ば",
+            0x3B => "び",
+            0x3C => "ぶ",
+            0x3D => "べ",
+            0x3E => "ぼ",
+            0x40 => "パ",
+            0x41 => "ピ",
+            0x42 => "プ",
+            0x43 => "ポ",
+            0x44 => "ぱ",
+            0x45 => "ぴ",
+            0x46 => "ぷ",
+            0x47 => "ぺ",
+            0x48 => "ぽ",
+            0x7F => " ",
+            0x80 => "ア",
+            0x81 => "イ",
+            0x82 => "ウ",
+            0x83 => "エ",
+            0x84 => "オ",
+            0x85 => "カ",
+            0x86 => "キ",
+            0x87 => "ク",
+            0x88 => "ケ",
+            0x89 => "コ",
+            0x8A => "サ",
+            0x8B => "シ",
+            0x8C => "ス",
+            0x8D => "セ",
+            0x8E => "ソ",
+            0x8F => "タ",
+            0x90 => "チ",
+            0x91 => "ツ",
+            0x92 => "テ",
+            0x93 => "ト",
+            0x94 => "ナ",
+            0x95 => "ニ",
+            0x96 => "ヌ",
+            0x97 => "ネ",
+            0x98 => "ノ",
+            0x99 => "ハ",
+            0x9A => "ヒ",
+            0x9B => "フ",
+            0x9C => "ホ",
+            0x9D => "マ",
+            0x9E => "ミ",
+            0x9F => "ム",
+            0xA0 => "メ",
+            0xA1 => "モ",
+            0xA2 => "ヤ",
+            0xA3 => "ユ",
+            0xA4 => "ヨ",
+            0xA5 => "ラ",
+            0xA6 => "ル",
+            0xA7 => "レ",
+            0xA8 => "ロ",
+            0xA9 => "ワ",
+            0xAA => "ヲ",
+            0xAB => "ン",
+            0xAC => "ッ",
+            0xAD => "ャ",
+            0xAE => "ュ",
+            0xAF => "ョ",
+            0xB0 => "ィ",
+            0xB1 => "あ",
+            0xB2 => "い",
+            0xB3 => "う",
+            0xB4 => "え",
+            0xB5 => "お",
+            0xB6 => "か",
+            0xB7 => "き",
+            0xB8 => "く",
+            0xB9 => "け",
+            0xBA => "こ",
+            0xBB => "さ",
+            0xBC => "し",
+            0xBD => "す",
+            0xBE => "せ",
+            0xBF => "そ",
+            0xC0 => "た",
+            0xC1 => "ち",
+            0xC2 => "つ",
+            0xC3 => "て",
+            0xC4 => "と",
+            0xC5 => "な",
+            0xC6 => "に",
+            0xC7 => "ぬ",
+            0xC8 => "ね",
+            0xC9 => "の",
+            0xCA => "は",
+            0xCB => "ひ",
+            0xCC => "ふ",
+            0xCD => "へ",
+            0xCE => "ほ",
+            0xCF => "ま",
+            0xD0 => "み",
+            0xD1 => "む",
+            0xD2 => "め",
+            0xD3 => "も",
+            0xD4 => "や",
+            0xD5 => "ゆ",
+            0xD6 => "よ",
+            0xD7 => "ら",
+            0xD8 => "り",
+            0xD9 => "る",
+            0xDA => "れ",
+            0xDB => "ろ",
+            0xDC => "わ",
+            0xDD => "を",
+            0xDE => "ん",
+            0xDF => "っ",
+            0xE0 => "ゃ",
+            0xE1 => "ゅ",
+            0xE2 => "ょ",
+            0xE3 => "ー",
+            0xE4 => "゜",
+            0xE5 => "゛",
+            0xE6 => "？",
+            0xE7 => "！",
+            0xE8 => "。",
+            0xE9 => "ァ",
+            0xEA => "ゥ",
+            0xEB => "ェ",
+            0xEC => "▷",
+            0xED => "▶",
+            0xEE => "▼",
+            0xEF => "♂",
+            0xF0 => "円",
+            0xF1 => "×",
+            0xF2 => ".",
+            0xF3 => "／",
+            0xF4 => "ォ",
+            0xF5 => "♀",
+            0xF6 => "0",
+            0xF7 => "1",
+            0xF8 => "2",
+            0xF9 => "3",
+            0xFA => "4",
+            0xFB => "5",
+            0xFC => "6",
+            0xFD => "7",
+            0xFE => "8",
+            0xFF => "9",
+            _    => " "
+        };
+
+        decodedText.push_str(decodedChar);
+    }
+
+    return decodedText;
+}
+
+/// Encodes text into the kana character set used by the Japanese games
+/// 
+/// The katakana ヘ, リ, ベ and ペ share a character with their hiragana, as they look the same,
+/// so they are encoded as the hiragana.
+pub fn kanaEncode(decoded: &String) -> Result<[i16; 11], String>{
+    let mut encoded: [i16; 11] = [TEXT_TERMINATOR; 11];
 
+    for (index, currChar) in decoded.chars().enumerate() {
+        // The last byte of a 6 byte name is always left for the terminator
+        if index >= 5 {
+            return Err(formatError(format!("Text \"{}\" is over 5 characters.", decoded)));
+        }
+
+        encoded[index] = match encodeKana(currChar) {
+            Some(encodedChar)   => encodedChar,
+            None                => return Err(formatError(format!("Character \"{}\" in \"{}\" can't be used in Japanese Gen 1 text", currChar, decoded)))
+        };
+    }
+
+    return Ok(encoded);
+}
+
+/// Encodes a single kana character, returning `None` if it isn't in the Japanese character set
+fn encodeKana(decoded: char) -> Option<i16> {
+    let encoded = match decoded {
+        'ガ' => 0x05,
+        'ギ' => 0x06,
+        'グ' => 0x07,
+        'ゲ' => 0x08,
+        'ゴ' => 0x09,
+        'ザ' => 0x0A,
+        'ジ' => 0x0B,
+        'ズ' => 0x0C,
+        'ゼ' => 0x0D,
+        'ゾ' => 0x0E,
+        'ダ' => 0x0F,
+        'ヂ' => 0x10,
+        'ヅ' => 0x11,
+        'デ' => 0x12,
+        'ド' => 0x13,
+        'バ' => 0x19,
+        'ビ' => 0x1A,
+        'ブ' => 0x1B,
+        'ボ' => 0x1C,
+        'が' => 0x26,
+        'ぎ' => 0x27,
+        'ぐ' => 0x28,
+        'げ' => 0x29,
+        'ご' => 0x2A,
+        'ざ' => 0x2B,
+        'じ' => 0x2C,
+        'ず' => 0x2D,
+        'ぜ' => 0x2E,
+        'ぞ' => 0x2F,
+        'だ' => 0x30,
+        'ぢ' => 0x31,
+        'づ' => 0x32,
+        'で' => 0x33,
+        'ど' => 0x34,
+        'ば' => 0x3A,
+        'び' => 0x3B,
+        'ぶ' => 0x3C,
+        'べ' => 0x3D,
+        'ぼ' => 0x3E,
+        'パ' => 0x40,
+        'ピ' => 0x41,
+        'プ' => 0x42,
+        'ポ' => 0x43,
+        'ぱ' => 0x44,
+        'ぴ' => 0x45,
+        'ぷ' => 0x46,
+        'ぺ' => 0x47,
+        'ぽ' => 0x48,
+        ' ' => 0x7F,
+        'ア' => 0x80,
+        'イ' => 0x81,
+        'ウ' => 0x82,
+        'エ' => 0x83,
+        'オ' => 0x84,
+        'カ' => 0x85,
+        'キ' => 0x86,
+        'ク' => 0x87,
+        'ケ' => 0x88,
+        'コ' => 0x89,
+        'サ' => 0x8A,
+        'シ' => 0x8B,
+        'ス' => 0x8C,
+        'セ' => 0x8D,
+        'ソ' => 0x8E,
+        'タ' => 0x8F,
+        'チ' => 0x90,
+        'ツ' => 0x91,
+        'テ' => 0x92,
+        'ト' => 0x93,
+        'ナ' => 0x94,
+        'ニ' => 0x95,
+        'ヌ' => 0x96,
+        'ネ' => 0x97,
+        'ノ' => 0x98,
+        'ハ' => 0x99,
+        'ヒ' => 0x9A,
+        'フ' => 0x9B,
+        'ホ' => 0x9C,
+        'マ' => 0x9D,
+        'ミ' => 0x9E,
+        'ム' => 0x9F,
+        'メ' => 0xA0,
+        'モ' => 0xA1,
+        'ヤ' => 0xA2,
+        'ユ' => 0xA3,
+        'ヨ' => 0xA4,
+        'ラ' => 0xA5,
+        'ル' => 0xA6,
+        'レ' => 0xA7,
+        'ロ' => 0xA8,
+        'ワ' => 0xA9,
+        'ヲ' => 0xAA,
+        'ン' => 0xAB,
+        'ッ' => 0xAC,
+        'ャ' => 0xAD,
+        'ュ' => 0xAE,
+        'ョ' => 0xAF,
+        'ィ' => 0xB0,
+        'あ' => 0xB1,
+        'い' => 0xB2,
+        'う' => 0xB3,
+        'え' => 0xB4,
+        'お' => 0xB5,
+        'か' => 0xB6,
+        'き' => 0xB7,
+        'く' => 0xB8,
+        'け' => 0xB9,
+        'こ' => 0xBA,
+        'さ' => 0xBB,
+        'し' => 0xBC,
+        'す' => 0xBD,
+        'せ' => 0xBE,
+        'そ' => 0xBF,
+        'た' => 0xC0,
+        'ち' => 0xC1,
+        'つ' => 0xC2,
+        'て' => 0xC3,
+        'と' => 0xC4,
+        'な' => 0xC5,
+        'に' => 0xC6,
+        'ぬ' => 0xC7,
+        'ね' => 0xC8,
+        'の' => 0xC9,
+        'は' => 0xCA,
+        'ひ' => 0xCB,
+        'ふ' => 0xCC,
+        'へ' => 0xCD,
+        'ほ' => 0xCE,
+        'ま' => 0xCF,
+        'み' => 0xD0,
+        'む' => 0xD1,
+        'め' => 0xD2,
+        'も' => 0xD3,
+        'や' => 0xD4,
+        'ゆ' => 0xD5,
+        'よ' => 0xD6,
+        'ら' => 0xD7,
+        'り' => 0xD8,
+        'る' => 0xD9,
+        'れ' => 0xDA,
+        'ろ' => 0xDB,
+        'わ' => 0xDC,
+        'を' => 0xDD,
+        'ん' => 0xDE,
+        'っ' => 0xDF,
+        'ゃ' => 0xE0,
+        'ゅ' => 0xE1,
+        'ょ' => 0xE2,
+        'ー' => 0xE3,
+        '゜' => 0xE4,
+        '゛' => 0xE5,
+        '？' => 0xE6,
+        '！' => 0xE7,
+        '。' => 0xE8,
+        'ァ' => 0xE9,
+        'ゥ' => 0xEA,
+        'ェ' => 0xEB,
+        '▷' => 0xEC,
+        '▶' => 0xED,
+        '▼' => 0xEE,
+        '♂' => 0xEF,
+        '円' => 0xF0,
+        '×' => 0xF1,
+        '.' => 0xF2,
+        '／' => 0xF3,
+        'ォ' => 0xF4,
+        '♀' => 0xF5,
+        '0' => 0xF6,
+        '1' => 0xF7,
+        '2' => 0xF8,
+        '3' => 0xF9,
+        '4' => 0xFA,
+        '5' => 0xFB,
+        '6' => 0xFC,
+        '7' => 0xFD,
+        '8' => 0xFE,
+        '9' => 0xFF,
+        'ヘ' => 0xCD,
+        'リ' => 0xD8,
+        'ベ' => 0x3D,
+        'ペ' => 0x47,
+        _   => return None
+    };
+
+    return Some(encoded);
+}
+
+/// Checks that the main data checksum matches, for the layout of the given region
+pub fn integrityCheck(saveFile: &Vec<u8>, layout: &SaveLayout) -> bool {
+    // We use the main data checksum
+    // https://m.bulbapedia.bulbagarden.net/wiki/Save_data_structure_(Generation_I)#bank1_checksum
+    return saveFile.len() > layout.checksumResult && calculateMainChecksum(saveFile, layout) == saveFile[layout.checksumResult];
+}
+
+/// Finds the layout of a save, by checking which region's main data checksum matches.
+/// 
+/// Returns `None` if neither matches, as the file is most likely not a Gen 1 save.
+pub fn detectLayout(saveFile: &Vec<u8>) -> Option<&'static SaveLayout> {
+    if integrityCheck(saveFile, &INTERNATIONAL) {
+        return Some(&INTERNATIONAL);
+    } else if integrityCheck(saveFile, &JAPANESE) {
+        return Some(&JAPANESE);
+    }
+
+    return None;
+}
+
+/// Calculates a Gen 1 checksum over a range of bytes.
+/// 
+/// We will be using the "easy" way, as shown in the
+/// Checksum section of the bulbapedia https://m.bulbapedia.bulbagarden.net/wiki/Save_data_structure_(Generation_I)#Checksum
+pub fn calculateChecksum(data: &[u8]) -> u8 {
     // We also need to use "Wrapping" here because the checksum relies
     // on integer over and underflow, but Rust does not allow that on default
     // integers due to type safety.
     let mut checksumVal: Wrapping<u8> = Wrapping(0);
-    
-    // We use the main data checksum
-    // https://m.bulbapedia.bulbagarden.net/wiki/Save_data_structure_(Generation_I)#bank1_checksum
-    let checksumRes: Wrapping<u8> = Wrapping(saveFile[CHECKSUM_RESULT]);
 
-    for byte in  CHECKSUM_START_ADDR..CHECKSUM_END_ADDR {
-        checksumVal += &saveFile[byte];
+    for byte in data {
+        checksumVal += byte;
     }
 
-    return !checksumVal == checksumRes;
-    
+    return (!checksumVal).0;
+}
+
+/// Calculates the main data checksum, which covers the player name through to the current box
+pub fn calculateMainChecksum(saveFile: &Vec<u8>, layout: &SaveLayout) -> u8 {
+    return calculateChecksum(&saveFile[CHECKSUM_START_ADDR..=layout.checksumEndAddr]);
+}
+
+/// Calculates the checksum of a whole PC bank, where `bank` is either 2 or 3
+pub fn calculateBankChecksum(saveFile: &Vec<u8>, layout: &SaveLayout, bank: usize) -> u8 {
+    let bankAddr = bank * BANK_SIZE;
+    return calculateChecksum(&saveFile[bankAddr..bankAddr+layout.bankChecksumOff]);
+}
+
+/// Calculates the checksum of a single PC box, where `pcBox` is 0-11 (0-7 in Japanese saves)
+pub fn calculateBoxChecksum(saveFile: &Vec<u8>, layout: &SaveLayout, pcBox: usize) -> u8 {
+    let boxAddr = layout.boxAddr(pcBox);
+    return calculateChecksum(&saveFile[boxAddr..boxAddr+layout.boxSize]);
+}
+
+/// Recalculates and stores every checksum in the save, so the game will accept it.
+/// 
+/// This is the main data checksum in bank 1, and both the bank and per-box checksums in banks 2 and 3.
+pub fn repairChecksums(saveFile: &mut Vec<u8>, layout: &SaveLayout) {
+    saveFile[layout.checksumResult] = calculateMainChecksum(saveFile, layout);
+
+    for bank in 2..4 {
+        let bankAddr = bank * BANK_SIZE;
+
+        saveFile[bankAddr+layout.bankChecksumOff] = calculateBankChecksum(saveFile, layout, bank);
+
+        for bankBox in 0..layout.boxesPerBank {
+            let pcBox = (bank-2)*layout.boxesPerBank + bankBox;
+            saveFile[bankAddr+layout.boxChecksumOff+bankBox] = calculateBoxChecksum(saveFile, layout, pcBox);
+        }
+    }
+}
+
+/// Calculates the Gen 2 main data checksum.
+/// 
+/// Unlike Gen 1, this is a 16 bit sum of every byte, stored little endian.
+/// https://bulbapedia.bulbagarden.net/wiki/Save_data_structure_(Generation_II)#Checksums
+pub fn calculateGen2Checksum(saveFile: &Vec<u8>, layout: &Gen2Layout) -> u16 {
+    let mut checksumVal: Wrapping<u16> = Wrapping(0);
+
+    for byte in &saveFile[GEN2_CHECKSUM_START_ADDR..=layout.checksumEndAddr] {
+        checksumVal += Wrapping(*byte as u16);
+    }
+
+    return checksumVal.0;
+}
+
+/// Checks that the Gen 2 main data checksum matches, for the layout of the given version
+pub fn gen2IntegrityCheck(saveFile: &Vec<u8>, layout: &Gen2Layout) -> bool {
+    if saveFile.len() <= layout.checksumAddr+1 {
+        return false;
+    }
+
+    let storedChecksum = u16::from_le_bytes([saveFile[layout.checksumAddr], saveFile[layout.checksumAddr+1]]);
+
+    return calculateGen2Checksum(saveFile, layout) == storedChecksum;
+}
+
+/// Finds the layout of a Gen 2 save, by checking which version's checksum matches.
+/// 
+/// Returns `None` if neither matches, as the file is most likely not a Gen 2 save.
+pub fn detectGen2Layout(saveFile: &Vec<u8>) -> Option<&'static Gen2Layout> {
+    if gen2IntegrityCheck(saveFile, &CRYSTAL) {
+        return Some(&CRYSTAL);
+    } else if gen2IntegrityCheck(saveFile, &GOLD_SILVER) {
+        return Some(&GOLD_SILVER);
+    }
+
+    return None;
 }
 
 /// A function for formatting error messages.
@@ -193,14 +791,14 @@ mod tests {
         // Letter "P"
         let mut letterList: [i16; 11] = [0x50; 11];
         letterList[0] = 0x8F;
-        assert_eq!(textDecode(&letterList),"P          ");
+        assert_eq!(textDecode(&letterList),"P");
     }
 
     #[test]
     fn textDecode_CapitalWord() {
         // Word "POKEMON"
         let word: [i16; 11] = [0x8F, 0x8E, 0x8A, 0x84, 0x8C, 0x8E, 0x8D, 0x50, 0x50, 0x50, 0x50];
-        assert_eq!(textDecode(&word), "POKEMON    ");
+        assert_eq!(textDecode(&word), "POKEMON");
     }
 
     #[test]
@@ -208,30 +806,97 @@ mod tests {
         // Letter "p"
         let mut letterList: [i16; 11] = [0x50; 11];
         letterList[0] = 0xAF;
-        assert_eq!(textDecode(&letterList), "p          ");
+        assert_eq!(textDecode(&letterList), "p");
     }
 
     #[test]
     fn textDecode_LowercaseWord() {
         // Word "pokemon"
         let word: [i16; 11] = [0xAF, 0xAE, 0xAA, 0xA4, 0xAC, 0xAE, 0xAD, 0x50, 0x50, 0x50, 0x50];
-        assert_eq!(textDecode(&word), "pokemon    ");
+        assert_eq!(textDecode(&word), "pokemon");
     }
 
     #[test]
     fn textDecode_MixedcaseWord() {
         // Word "Pokemon"
         let word: [i16; 11] = [0x8F, 0xAE, 0xAA, 0xA4, 0xAC, 0xAE, 0xAD, 0x50, 0x50, 0x50, 0x50];
-        assert_eq!(textDecode(&word), "Pokemon    ");
+        assert_eq!(textDecode(&word), "Pokemon");
     }
 
     #[test]
     fn textDecode_SpecialChar() {
         // Word "Pokemon"
         let word: [i16; 11] = [0x8F, 0xAE, 0xAA, 0xBA, 0xAC, 0xAE, 0xAD, 0x50, 0x50, 0x50, 0x50];
-        assert_eq!(textDecode(&word), "Pokémon    ");
+        assert_eq!(textDecode(&word), "Pokémon");
+    }
+
+
+    #[test]
+    fn textDecode_DigitsAndSymbols() {
+        // "No.1 PK♂!"
+        let word: [i16; 11] = [0x8D, 0xAE, 0xE8, 0xF7, 0x7F, 0xE1, 0xEF, 0xE7, 0x50, 0x50, 0x50];
+        assert_eq!(textDecode(&word), "No.1 ᴾᴷ♂!");
+    }
+
+    #[test]
+    fn textDecode_StopsAtTerminator() {
+        // "RED", followed by leftover data from a longer name
+        let word: [i16; 11] = [0x91, 0x84, 0x83, 0x50, 0x8E, 0x8D, 0x50, 0x00, 0x00, 0x00, 0x00];
+        assert_eq!(textDecode(&word), "RED");
+    }
+
+    #[test]
+    fn textEncode_Contractions() {
+        // "Farfetch'd", where 'd is a single character
+        let word: [i16; 11] = [0x85, 0xA0, 0xB1, 0xA5, 0xA4, 0xB3, 0xA2, 0xA7, 0xBB, 0x50, 0x50];
+        assert_eq!(textDecode(&word), "Farfetch'd");
+        assert_eq!(textEncode(&String::from("Farfetch'd")), Ok(word));
+    }
+
+    #[test]
+    fn textEncode_RoundTrip() {
+        // Every character that can be decoded is encoded back to the same byte
+        for char in 0x7F..=0xFF {
+            let mut encoded: [i16; 11] = [TEXT_TERMINATOR; 11];
+            encoded[0] = char;
+            let decoded = textDecode(&encoded);
+
+            if decoded == " " && char != 0x7F {
+                continue;
+            }
+
+            assert_eq!(textEncode(&decoded), Ok(encoded), "Character 0x{:02X} \"{}\"", char, decoded);
+        }
+    }
+
+    #[test]
+    fn kanaEncode_RoundTrip() {
+        for char in 0x00..=0xFF {
+            let mut encoded: [i16; 11] = [TEXT_TERMINATOR; 11];
+            encoded[0] = char;
+            let decoded = kanaDecode(&encoded);
+
+            if decoded.is_empty() || (decoded == " " && char != 0x7F) {
+                continue;
+            }
+
+            assert_eq!(kanaEncode(&decoded), Ok(encoded), "Character 0x{:02X} \"{}\"", char, decoded);
+        }
     }
 
+    #[test]
+    fn kanaDecode_Katakana() {
+        // "ピカチュウ"
+        let word: [i16; 11] = [0x41, 0x85, 0x90, 0xAE, 0x82, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50];
+        assert_eq!(kanaDecode(&word), "ピカチュウ");
+    }
+
+    #[test]
+    fn kanaDecode_Hiragana() {
+        // "さとし", followed by leftover data
+        let word: [i16; 11] = [0xBB, 0xC4, 0xBC, 0x50, 0x80, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00];
+        assert_eq!(kanaDecode(&word), "さとし");
+    }
 
     #[test]
     fn integrityCheck_Correct_PkmnYellow() {
@@ -239,7 +904,7 @@ mod tests {
 
         for filePath in testFiles {
             let saveFile = fs::read(filePath).unwrap();
-            let fileHasIntegrity = integrityCheck(&saveFile);
+            let fileHasIntegrity = integrityCheck(&saveFile, &INTERNATIONAL);
     
             assert_eq!(fileHasIntegrity, true);
         }
@@ -252,19 +917,116 @@ mod tests {
 
         for filePath in testFiles {
             let saveFile = fs::read(filePath).unwrap();
-            let fileHasIntegrity = integrityCheck(&saveFile);
+            let fileHasIntegrity = integrityCheck(&saveFile, &INTERNATIONAL);
     
             assert_eq!(fileHasIntegrity, true);
         }
 
     }
 
+    #[test]
+    fn calculateBankChecksum_Correct() {
+        let saveFile = fs::read("./test/POKEpi.sav").unwrap();
+
+        assert_eq!(calculateBankChecksum(&saveFile, &INTERNATIONAL, 2), saveFile[0x5A4C]);
+        assert_eq!(calculateBankChecksum(&saveFile, &INTERNATIONAL, 3), saveFile[0x7A4C]);
+    }
+
+    #[test]
+    fn calculateBoxChecksum_Correct() {
+        let saveFile = fs::read("./test/POKEpi.sav").unwrap();
+
+        for pcBox in 0..6 {
+            assert_eq!(calculateBoxChecksum(&saveFile, &INTERNATIONAL, pcBox), saveFile[0x5A4D+pcBox]);
+            assert_eq!(calculateBoxChecksum(&saveFile, &INTERNATIONAL, pcBox+6), saveFile[0x7A4D+pcBox]);
+        }
+    }
+
+    #[test]
+    fn repairChecksums_FixesEditedSave() {
+        let mut saveFile = fs::read("./test/POKEMON BLUE.sav").unwrap();
+
+        // Change a byte in the main data, and a byte in the first box
+        saveFile[MONEY_ADDR] = 0x99;
+        saveFile[PC_ADDR+PC_PKMN_OFF] = 0x99;
+        assert_eq!(integrityCheck(&saveFile, &INTERNATIONAL), false);
+
+        repairChecksums(&mut saveFile, &INTERNATIONAL);
+
+        assert_eq!(integrityCheck(&saveFile, &INTERNATIONAL), true);
+        assert_eq!(calculateBankChecksum(&saveFile, &INTERNATIONAL, 2), saveFile[0x5A4C]);
+        assert_eq!(calculateBoxChecksum(&saveFile, &INTERNATIONAL, 0), saveFile[0x5A4D]);
+    }
+
+    #[test]
+    fn repairChecksums_Japanese() {
+        let mut saveFile = vec![0; SAVE_SIZE];
+        saveFile[JAPANESE.nameAddr] = 0x80;
+
+        repairChecksums(&mut saveFile, &JAPANESE);
+
+        assert_eq!(integrityCheck(&saveFile, &JAPANESE), true);
+        // The bank checksum comes straight after the 4 boxes
+        assert_eq!(calculateBankChecksum(&saveFile, &JAPANESE, 3), saveFile[0x7598]);
+        assert_eq!(calculateBoxChecksum(&saveFile, &JAPANESE, 5), saveFile[0x759A]);
+    }
+
+    #[test]
+    fn detectLayout_International() {
+        let saveFile = fs::read("./test/POKEMON YELLOW.sav").unwrap();
+
+        assert_eq!(detectLayout(&saveFile).unwrap().region, GameRegion::International);
+    }
+
+    #[test]
+    fn detectLayout_Japanese() {
+        let mut saveFile = vec![0; SAVE_SIZE];
+        saveFile[JAPANESE.nameAddr] = 0x80;
+        repairChecksums(&mut saveFile, &JAPANESE);
+
+        assert_eq!(detectLayout(&saveFile).unwrap().region, GameRegion::Japanese);
+    }
+
+    #[test]
+    fn detectLayout_Incorrect() {
+        let saveFile = fs::read("./test/POKEMON CRYSTAL.sav").unwrap();
+
+        assert!(detectLayout(&saveFile).is_none());
+    }
+
+    #[test]
+    fn gen2IntegrityCheck_Correct_PkmnCrystal() {
+        let saveFile = fs::read("./test/POKEMON CRYSTAL.sav").unwrap();
+
+        assert_eq!(calculateGen2Checksum(&saveFile, &CRYSTAL), 0xC2A2);
+        assert_eq!(gen2IntegrityCheck(&saveFile, &CRYSTAL), true);
+        assert_eq!(gen2IntegrityCheck(&saveFile, &GOLD_SILVER), false);
+    }
+
+    #[test]
+    fn gen2IntegrityCheck_Incorrect_PkmnYellow() {
+        let saveFile = fs::read("./test/POKEMON YELLOW.sav").unwrap();
+
+        assert!(detectGen2Layout(&saveFile).is_none());
+    }
+
+    #[test]
+    fn detectGen2Layout_GoldSilver() {
+        let mut saveFile = vec![0; SAVE_SIZE];
+        saveFile[GOLD_SILVER.nameAddr] = 0x80;
+        let checksum = calculateGen2Checksum(&saveFile, &GOLD_SILVER).to_le_bytes();
+        saveFile[GOLD_SILVER.checksumAddr] = checksum[0];
+        saveFile[GOLD_SILVER.checksumAddr+1] = checksum[1];
+
+        assert_eq!(detectGen2Layout(&saveFile).unwrap().name, "Gold/Silver");
+    }
+
     #[test]
     fn integrityCheck_Incorrect_PkmnCrystal() {
 
         let filePath = "./test/POKEMON CRYSTAL.sav";
         let saveFile = fs::read(filePath).unwrap();
-        let fileHasIntegrity = integrityCheck(&saveFile);
+        let fileHasIntegrity = integrityCheck(&saveFile, &INTERNATIONAL);
 
         assert_eq!(fileHasIntegrity, false);
     }
@@ -272,57 +1034,118 @@ mod tests {
     #[test]
     fn textEncode_UppercaseLetter() {
         // Letter "P"
-        let decodedText = String::from("P          ");
+        let decodedText = String::from("P");
 
         let mut letterList: [i16; 11] = [0x50; 11];
         letterList[0] = 0x8F;
-        assert_eq!(textEncode(&decodedText), letterList);
+        assert_eq!(textEncode(&decodedText).unwrap(), letterList);
     }
 
     #[test]
     fn textEncode_CapitalWord() {
         // Word "POKEMON"
-        let decodedText = String::from("POKEMON    ");
+        let decodedText = String::from("POKEMON");
         let word: [i16; 11] = [0x8F, 0x8E, 0x8A, 0x84, 0x8C, 0x8E, 0x8D, 0x50, 0x50, 0x50, 0x50];
 
-        assert_eq!(textEncode(&decodedText), word);
+        assert_eq!(textEncode(&decodedText).unwrap(), word);
     }
 
     #[test]
     fn textEncode_LowercaseLetter() {
         // Letter "p"
-        let decodedText = String::from("p          ");
+        let decodedText = String::from("p");
 
         let mut letterList: [i16; 11] = [0x50; 11];
         letterList[0] = 0xAF;
-        assert_eq!(textEncode(&decodedText), letterList);
+        assert_eq!(textEncode(&decodedText).unwrap(), letterList);
     }
 
     #[test]
     fn textEncode_LowercaseWord() {
         // Word "pokemon"
-        let decodedText = String::from("pokemon    ");
+        let decodedText = String::from("pokemon");
 
         let word: [i16; 11] = [0xAF, 0xAE, 0xAA, 0xA4, 0xAC, 0xAE, 0xAD, 0x50, 0x50, 0x50, 0x50];
-        assert_eq!(textEncode(&decodedText), word);
+        assert_eq!(textEncode(&decodedText).unwrap(), word);
     }
 
     #[test]
     fn textEncode_MixedcaseWord() {
         // Word "Pokemon"
-        let decodedText = String::from("Pokemon    ");
+        let decodedText = String::from("Pokemon");
 
         let word: [i16; 11] = [0x8F, 0xAE, 0xAA, 0xA4, 0xAC, 0xAE, 0xAD, 0x50, 0x50, 0x50, 0x50];
-        assert_eq!(textEncode(&decodedText), word);
+        assert_eq!(textEncode(&decodedText).unwrap(), word);
     }
     
     #[test]
     fn textEncode_SpecialChar() {
         // Word "Pokemon"
-        let decodedText = String::from("Pokémon    ");
+        let decodedText = String::from("Pokémon");
 
         let word: [i16; 11] = [0x8F, 0xAE, 0xAA, 0xBA, 0xAC, 0xAE, 0xAD, 0x50, 0x50, 0x50, 0x50];
-        assert_eq!(textEncode(&decodedText), word);
+        assert_eq!(textEncode(&decodedText).unwrap(), word);
+    }
+
+    #[test]
+    fn textEncode_DigitsAndSymbols() {
+        let decodedText = String::from("No.1 ᴾᴷ♂!");
+
+        let word: [i16; 11] = [0x8D, 0xAE, 0xE8, 0xF7, 0x7F, 0xE1, 0xEF, 0xE7, 0x50, 0x50, 0x50];
+        assert_eq!(textEncode(&decodedText).unwrap(), word);
+    }
+
+    #[test]
+    fn textEncode_IncorrectChar() {
+        let decodedText = String::from("Red#");
+
+        let encodeResult = textEncode(&decodedText);
+
+        assert!(encodeResult.is_err());
+        assert_eq!(encodeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Character \"#\" in \"Red#\" can't be used in Gen 1 text");
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn textEncode_IncorrectLength() {
+        let decodedText = String::from("Jimbosaurus");
+
+        let encodeResult = textEncode(&decodedText);
+
+        assert!(encodeResult.is_err());
+        assert_eq!(encodeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Text \"Jimbosaurus\" is over 10 characters.");
+    }
+
+    #[test]
+    fn kanaEncode_Correct() {
+        let decodedText = String::from("レッド");
+
+        let word: [i16; 11] = [0xA7, 0xAC, 0x13, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50];
+        assert_eq!(kanaEncode(&decodedText).unwrap(), word);
+    }
+
+    #[test]
+    fn kanaEncode_SharedKana() {
+        // The katakana リ is written with the hiragana り
+        let decodedText = String::from("リザード");
+
+        let word: [i16; 11] = [0xD8, 0x0A, 0xE3, 0x13, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50];
+        assert_eq!(kanaEncode(&decodedText).unwrap(), word);
+    }
+
+    #[test]
+    fn kanaEncode_IncorrectChar() {
+        let encodeResult = kanaEncode(&String::from("Red"));
+
+        assert!(encodeResult.is_err());
+        assert_eq!(encodeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Character \"R\" in \"Red\" can't be used in Japanese Gen 1 text");
+    }
+
+    #[test]
+    fn kanaEncode_IncorrectLength() {
+        let encodeResult = kanaEncode(&String::from("フシギバナー"));
+
+        assert!(encodeResult.is_err());
+        assert_eq!(encodeResult.unwrap_err(), "\u{1b}[0;31mError\u{1b}[0m: Text \"フシギバナー\" is over 5 characters.");
+    }
+
+}
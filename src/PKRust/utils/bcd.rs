@@ -0,0 +1,114 @@
+//! Codec for [Binary Coded Decimal](https://en.wikipedia.org/wiki/Binary-coded_decimal),
+//! which is what Gen 1 uses for storing money and coins.
+//!
+//! Each byte holds two decimal digits, one per nibble, with the most significant byte first.
+
+#[derive(Debug, PartialEq)]
+pub enum BCDError {
+    /// A nibble held a value over 9, which isn't a decimal digit
+    InvalidNibble { byte: usize, value: u8 },
+    /// The value has too many digits to fit into the amount of bytes
+    Overflow { value: u32, bytes: usize },
+}
+impl BCDError {
+    /// Returns a message describing the error
+    pub fn to_string(&self) -> String {
+        return match self {
+            BCDError::InvalidNibble { byte, value } => format!("Byte {} (0x{:02X}) is not a valid BCD value", byte, value),
+            BCDError::Overflow { value, bytes }     => format!("Value \"{}\" does not fit into {} BCD bytes", value, bytes),
+        };
+    }
+}
+
+/// Decodes BCD bytes into a number
+pub fn decode(encoded: &[u8]) -> Result<u32, BCDError> {
+    let mut decoded: u32 = 0;
+
+    for (byte, value) in encoded.iter().enumerate() {
+        let highDigit = value >> 4;
+        let lowDigit = value & 0x0F;
+
+        // Nibbles A-F aren't decimal digits, so the data is corrupt
+        if highDigit > 9 || lowDigit > 9 {
+            return Err(BCDError::InvalidNibble { byte, value: *value });
+        }
+
+        decoded = (decoded * 100) + (highDigit as u32 * 10) + lowDigit as u32;
+    }
+
+    return Ok(decoded);
+}
+
+/// Encodes a number into an amount of BCD bytes
+pub fn encode(value: u32, bytes: usize) -> Result<Vec<u8>, BCDError> {
+    let mut encoded: Vec<u8> = vec![0; bytes];
+    let mut remaining = value;
+
+    for byte in (0..bytes).rev() {
+        let lowDigit = (remaining % 10) as u8;
+        let highDigit = ((remaining / 10) % 10) as u8;
+
+        encoded[byte] = (highDigit << 4) | lowDigit;
+        remaining /= 100;
+    }
+
+    // Anything left over didn't fit into the bytes
+    if remaining != 0 {
+        return Err(BCDError::Overflow { value, bytes });
+    }
+
+    return Ok(encoded);
+}
+
+#[cfg(test)]
+mod bcdTests {
+    use super::*;
+
+    #[test]
+    fn decode_Money() {
+        assert_eq!(decode(&[0x69, 0x58, 0x98]), Ok(695_898));
+    }
+
+    #[test]
+    fn decode_Coins() {
+        assert_eq!(decode(&[0x06, 0x89]), Ok(689));
+    }
+
+    #[test]
+    fn decode_InvalidNibble() {
+        let decodeResult = decode(&[0x00, 0x1A, 0x00]);
+
+        assert_eq!(decodeResult, Err(BCDError::InvalidNibble { byte: 1, value: 0x1A }));
+        assert_eq!(decodeResult.unwrap_err().to_string(), "Byte 1 (0x1A) is not a valid BCD value");
+    }
+
+    #[test]
+    fn encode_Money() {
+        assert_eq!(encode(695_898, 3), Ok(vec![0x69, 0x58, 0x98]));
+    }
+
+    #[test]
+    fn encode_LeadingZeroes() {
+        assert_eq!(encode(1455, 3), Ok(vec![0x00, 0x14, 0x55]));
+    }
+
+    #[test]
+    fn encode_Coins() {
+        assert_eq!(encode(9999, 2), Ok(vec![0x99, 0x99]));
+    }
+
+    #[test]
+    fn encode_Overflow() {
+        let encodeResult = encode(10_000, 2);
+
+        assert_eq!(encodeResult, Err(BCDError::Overflow { value: 10_000, bytes: 2 }));
+        assert_eq!(encodeResult.unwrap_err().to_string(), "Value \"10000\" does not fit into 2 BCD bytes");
+    }
+
+    #[test]
+    fn encode_RoundTrip() {
+        for value in [0, 7, 10, 99, 100, 4242, 9999] {
+            assert_eq!(decode(&encode(value, 2).unwrap()), Ok(value));
+        }
+    }
+}
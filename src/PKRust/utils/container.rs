@@ -0,0 +1,138 @@
+//! Detection of the file format a save is stored in.
+//!
+//! The games only use 32 KiB of SRAM, but the files emulators and cartridge dumpers make
+//! can have a real time clock footer after it, or stop before the end of the last bank.
+//! The SRAM is taken out of the file for loading, and put back into the same format for writing.
+
+use super::super::addresses::SAVE_SIZE;
+
+/// Sizes of the real time clock footers added by emulators, such as VBA-M and BGB
+const RTC_FOOTER_SIZES: [usize; 2] = [0x2C, 0x30];
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ContainerFormat {
+    /// A plain 32 KiB SRAM image, such as a `.sav` or `.srm`
+    Raw,
+    /// 32 KiB of SRAM followed by an emulator real time clock footer
+    RTCFooter,
+    /// SRAM that stops before the end of the last bank
+    Truncated,
+}
+
+#[derive(Debug, PartialEq)]
+/// The format a save file was in, with whatever is needed to write it back the same way
+pub struct SaveContainer {
+    format: ContainerFormat,
+    /// The size of the file when it was read
+    fileSize: usize,
+    /// Anything after the SRAM, which is kept as it was
+    footer: Vec<u8>,
+}
+impl SaveContainer {
+
+    /// Constructor for a plain 32 KiB SRAM container, for saves that weren't read from a file
+    pub fn new() -> SaveContainer {
+        return SaveContainer{format: ContainerFormat::Raw, fileSize: SAVE_SIZE, footer: Vec::new()};
+    }
+
+    /// Detects the format of a save file, and returns it along with the 32 KiB SRAM image.
+    ///
+    /// Truncated files are padded out with 0s, so every address can be read.
+    pub fn detect(file: &Vec<u8>) -> Result<(SaveContainer, Vec<u8>), String> {
+        let fileSize = file.len();
+        let mut sram = file[..fileSize.min(SAVE_SIZE)].to_vec();
+
+        let format = if fileSize == SAVE_SIZE {
+            ContainerFormat::Raw
+        } else if fileSize < SAVE_SIZE {
+            sram.resize(SAVE_SIZE, 0);
+            ContainerFormat::Truncated
+        } else if RTC_FOOTER_SIZES.contains(&(fileSize - SAVE_SIZE)) {
+            ContainerFormat::RTCFooter
+        } else {
+            return Err(format!("File is {} bytes, which isn't a known save format", fileSize));
+        };
+
+        let footer = file[fileSize.min(SAVE_SIZE)..].to_vec();
+
+        return Ok((SaveContainer{format, fileSize, footer}, sram));
+    }
+
+    /// Puts a 32 KiB SRAM image back into the format the file was read in
+    pub fn wrap(&self, sram: &Vec<u8>) -> Vec<u8> {
+        let mut file = sram.clone();
+
+        match self.format {
+            ContainerFormat::Raw        => (),
+            ContainerFormat::RTCFooter  => file.extend_from_slice(&self.footer),
+            ContainerFormat::Truncated  => file.truncate(self.fileSize),
+        }
+
+        return file;
+    }
+
+    /// Returns a description of the format, for showing to the user
+    pub fn to_string(&self) -> String {
+        return match self.format {
+            ContainerFormat::Raw        => String::from("Raw 32 KiB SRAM"),
+            ContainerFormat::RTCFooter  => format!("32 KiB SRAM with a {} byte RTC footer", self.footer.len()),
+            ContainerFormat::Truncated  => format!("Truncated SRAM ({} of {} bytes)", self.fileSize, SAVE_SIZE),
+        };
+    }
+
+    pub fn getFormat(&self) -> &ContainerFormat {
+        return &self.format;
+    }
+
+    pub fn getFileSize(&self) -> &usize {
+        return &self.fileSize;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn detect_Raw() {
+        let file = fs::read("./test/POKEMON YELLOW.sav").unwrap();
+        let (container, sram) = SaveContainer::detect(&file).unwrap();
+
+        assert_eq!(container.getFormat(), &ContainerFormat::Raw);
+        assert_eq!(container.to_string(), "Raw 32 KiB SRAM");
+        assert_eq!(sram, file);
+    }
+
+    #[test]
+    fn detect_RTCFooter() {
+        let file = fs::read("./test/POKEMON CRYSTAL.sav").unwrap();
+        let (container, sram) = SaveContainer::detect(&file).unwrap();
+
+        assert_eq!(container.getFormat(), &ContainerFormat::RTCFooter);
+        assert_eq!(container.to_string(), "32 KiB SRAM with a 48 byte RTC footer");
+        assert_eq!(sram.len(), SAVE_SIZE);
+        assert_eq!(container.wrap(&sram), file);
+    }
+
+    #[test]
+    fn detect_Truncated() {
+        let file = fs::read("./test/POKEpi.sav").unwrap();
+        let (container, sram) = SaveContainer::detect(&file).unwrap();
+
+        assert_eq!(container.getFormat(), &ContainerFormat::Truncated);
+        assert_eq!(container.to_string(), "Truncated SRAM (31315 of 32768 bytes)");
+        // The missing bytes are filled in, and removed again when writing
+        assert_eq!(sram.len(), SAVE_SIZE);
+        assert_eq!(container.wrap(&sram), file);
+    }
+
+    #[test]
+    fn detect_Unknown() {
+        let file = vec![0; SAVE_SIZE + 0x100];
+
+        assert_eq!(SaveContainer::detect(&file).unwrap_err(), "File is 33024 bytes, which isn't a known save format");
+    }
+}
@@ -3,11 +3,27 @@
 // be mutable for all the editing functions, just they
 // aren't implemneted into the main "program" yet.
 #![allow(unused_mut)]
+// The codebase is written with explicit returns, `&Vec` parameters, index loops,
+// `to_string` methods and `assert_eq!` against bools, so those lints are silenced.
+// The older code also borrows `&save` twice, checks `is_err()` before `unwrap_err()`,
+// zero pads move IDs and imports `std::u16`, which is left as it was written.
+#![allow(
+    clippy::needless_return,
+    clippy::ptr_arg,
+    clippy::needless_range_loop,
+    clippy::inherent_to_string,
+    clippy::bool_assert_comparison,
+    clippy::needless_borrow,
+    clippy::unnecessary_unwrap,
+    clippy::zero_prefixed_literal,
+    clippy::legacy_numeric_constants
+)]
 
 pub mod PKRust;
 use std::{io::{self, Write}, process};
 
 use PKRust::saveLoader::Save;
+use PKRust::gen2SaveLoader::Gen2Save;
 
 fn main() {
 
@@ -24,9 +40,13 @@ fn main() {
     let mut saveFile = match Save::load(saveLoc) {
         // Get the correct Save if no errors
         Ok(correctSave) => correctSave,
-        // Print the error message and exit the program
-        // Exits with status 1 as it is a "general error"
-        Err(error) => {eprintln!("{}",error); process::exit(1);}
+        // Gold, Silver and Crystal saves are loaded on their own
+        Err(error) => match Gen2Save::load(saveLoc) {
+            Ok(gen2Save) => {gen2Save.print(); return;}
+            // Print the error message and exit the program
+            // Exits with status 1 as it is a "general error"
+            Err(_) => {eprintln!("{}",error); process::exit(1);}
+        }
 
     };
     saveFile.print();